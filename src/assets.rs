@@ -0,0 +1,214 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use crate::{
+    error::Errors,
+    render::{self, Token},
+};
+
+pub(crate) fn image_sources(html: &str) -> Vec<String> {
+    let mut sources = Vec::new();
+    for token in render::tokenize(html) {
+        if let Token::Open(name, attrs) = token {
+            if name != "img" {
+                continue;
+            }
+            if let Some((_, src)) = attrs.iter().find(|(key, _)| key == "src") {
+                if !src.is_empty() && !src.starts_with("data:") && !sources.contains(src) {
+                    sources.push(src.clone());
+                }
+            }
+        }
+    }
+    sources
+}
+
+pub(crate) async fn download_images(html: &str, dir: &Path) -> Result<String, Errors> {
+    let sources = image_sources(html);
+    if sources.is_empty() {
+        return Ok(String::from(html));
+    }
+
+    tokio::fs::create_dir_all(dir).await?;
+
+    let link_dir = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let client = reqwest::Client::new();
+    let mut used_names = HashSet::new();
+    let mut links = HashMap::new();
+
+    for src in sources {
+        let Some(url) = absolute_url(&src) else {
+            continue;
+        };
+        let file_name = unique_file_name(&src, &mut used_names);
+        let bytes = client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        tokio::fs::write(dir.join(&file_name), bytes).await?;
+
+        let link = if link_dir.is_empty() {
+            file_name
+        } else {
+            format!("{}/{}", link_dir, file_name)
+        };
+        links.insert(src, link);
+    }
+
+    Ok(rewrite_image_sources(html, &links))
+}
+
+fn rewrite_image_sources(html: &str, links: &HashMap<String, String>) -> String {
+    let lower = html.to_ascii_lowercase();
+    let mut rewritten = String::with_capacity(html.len());
+    let mut pos = 0;
+
+    while let Some(start) = lower[pos..].find("<img").map(|start| pos + start) {
+        let end = lower[start..]
+            .find('>')
+            .map_or(html.len(), |end| start + end);
+        rewritten.push_str(&html[pos..start]);
+        match src_span(&lower[start..end]) {
+            Some((value_start, value_end)) => {
+                let raw = &html[start + value_start..start + value_end];
+                let link = links.get(&render::decode_entities(raw));
+                rewritten.push_str(&html[start..start + value_start]);
+                rewritten.push_str(link.map_or(raw, String::as_str));
+                rewritten.push_str(&html[start + value_end..end]);
+            }
+            None => rewritten.push_str(&html[start..end]),
+        }
+        pos = end;
+    }
+
+    rewritten.push_str(&html[pos..]);
+    rewritten
+}
+
+fn src_span(tag: &str) -> Option<(usize, usize)> {
+    let mut search = 0;
+    while let Some(found) = tag[search..].find("src").map(|found| search + found) {
+        search = found + 3;
+        if !tag[..found].ends_with(|ch: char| ch.is_whitespace()) {
+            continue;
+        }
+        let after = &tag[search..];
+        let Some(value) = after.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let value_start = tag.len() - value.len();
+
+        return Some(match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let len = value[1..].find(quote).unwrap_or(value.len() - 1);
+                (value_start + 1, value_start + 1 + len)
+            }
+            _ => {
+                let len = value
+                    .find(|ch: char| ch.is_whitespace())
+                    .unwrap_or(value.trim_end_matches('/').len());
+                (value_start, value_start + len)
+            }
+        });
+    }
+    None
+}
+
+fn absolute_url(src: &str) -> Option<reqwest::Url> {
+    let url = reqwest::Url::parse("https://leetcode.com/")
+        .ok()?
+        .join(src)
+        .ok()?;
+    matches!(url.scheme(), "http" | "https").then_some(url)
+}
+
+fn unique_file_name(src: &str, used_names: &mut HashSet<String>) -> String {
+    let path = src.split(['?', '#']).next().unwrap_or_default();
+    let name = path
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '.' || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let name = if name.is_empty() {
+        String::from("image")
+    } else {
+        name
+    };
+
+    let mut candidate = name.clone();
+    let mut index = 1;
+    while !used_names.insert(candidate.clone()) {
+        candidate = format!("{}_{}", index, name);
+        index += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{absolute_url, image_sources, rewrite_image_sources};
+
+    #[test]
+    fn rewrites_entity_encoded_sources() {
+        let html = r#"<p>See <IMG alt="a" src="https://x.com/a.png?v=1&amp;w=2" /> and <img src=/b.png/> <img data-src="c.png" src='c.png'></p>"#;
+        let sources = image_sources(html);
+        assert_eq!(
+            sources,
+            vec!["https://x.com/a.png?v=1&w=2", "/b.png", "c.png"]
+        );
+
+        let links = sources
+            .into_iter()
+            .zip(["images/a.png", "images/b.png", "images/c.png"])
+            .map(|(src, link)| (src, String::from(link)))
+            .collect::<HashMap<String, String>>();
+
+        assert_eq!(
+            rewrite_image_sources(html, &links),
+            r#"<p>See <IMG alt="a" src="images/a.png" /> and <img src=images/b.png/> <img data-src="c.png" src='images/c.png'></p>"#
+        );
+    }
+
+    #[test]
+    fn resolves_sources_against_leetcode() {
+        let resolve = |src: &str| absolute_url(src).map(|url| url.to_string());
+
+        assert_eq!(
+            resolve("https://assets.leetcode.com/a.png").as_deref(),
+            Some("https://assets.leetcode.com/a.png")
+        );
+        assert_eq!(
+            resolve("//assets.leetcode.com/b.png").as_deref(),
+            Some("https://assets.leetcode.com/b.png")
+        );
+        assert_eq!(
+            resolve("/static/c.png").as_deref(),
+            Some("https://leetcode.com/static/c.png")
+        );
+        assert_eq!(
+            resolve("c.png").as_deref(),
+            Some("https://leetcode.com/c.png")
+        );
+        assert_eq!(resolve("ftp://example.com/d.png"), None);
+    }
+}
@@ -0,0 +1,502 @@
+use crate::{
+    error::Errors,
+    problem_actions::Problem,
+    resources::contest::{
+        ContestDetail, ContestQuestion, ContestRankingPage, PastContest, RankingEntry,
+        UpcomingContest, UserContestHistoryNode, UserContestRanking,
+    },
+    UserApi,
+};
+
+pub(crate) const INITIAL_RATING: f64 = 1500.0;
+
+const WRONG_SUBMISSION_PENALTY: i64 = 5 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContestSeries {
+    Weekly(u32),
+    Biweekly(u32),
+    Other,
+}
+
+impl ContestSeries {
+    pub fn from_slug(title_slug: &str) -> ContestSeries {
+        let number = |prefix: &str| {
+            title_slug
+                .strip_prefix(prefix)
+                .and_then(|number| number.parse::<u32>().ok())
+        };
+
+        if let Some(number) = number("weekly-contest-") {
+            ContestSeries::Weekly(number)
+        } else if let Some(number) = number("biweekly-contest-") {
+            ContestSeries::Biweekly(number)
+        } else {
+            ContestSeries::Other
+        }
+    }
+
+    pub fn number(&self) -> Option<u32> {
+        match self {
+            ContestSeries::Weekly(number) | ContestSeries::Biweekly(number) => Some(*number),
+            ContestSeries::Other => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ContestResult {
+    pub title: String,
+    pub start_time: i64,
+    pub series: ContestSeries,
+    pub rank: u32,
+    pub rating: f64,
+    pub rating_change: f64,
+    pub problems_solved: u32,
+    pub total_problems: u32,
+    pub finish_time_in_seconds: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProblemFinish {
+    pub question_id: u32,
+    pub finished_at: i64,
+    pub fail_count: u32,
+    pub penalty_seconds: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardRow {
+    pub rank: u32,
+    pub username: String,
+    pub score: u32,
+    pub finish_time: i64,
+    pub problems: Vec<ProblemFinish>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Participant {
+    pub username: String,
+    pub rank: u32,
+    pub rating: f64,
+    pub attended_contests: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RatingPrediction {
+    pub username: String,
+    pub old_rating: f64,
+    pub new_rating: f64,
+    pub delta: f64,
+}
+
+#[derive(Debug)]
+pub struct Contest<'a> {
+    pub(crate) api: &'a UserApi,
+    pub detail: ContestDetail,
+}
+
+impl Contest<'_> {
+    pub fn title(&self) -> &str {
+        &self.detail.contest.title
+    }
+
+    pub fn start_time(&self) -> i64 {
+        self.detail.contest.start_time
+    }
+
+    pub fn duration(&self) -> i64 {
+        self.detail.contest.duration
+    }
+
+    pub fn series(&self) -> ContestSeries {
+        ContestSeries::from_slug(&self.detail.contest.title_slug)
+    }
+
+    pub fn questions(&self) -> &[ContestQuestion] {
+        &self.detail.questions
+    }
+
+    pub async fn ranking(&self, page: u32) -> Result<ContestRankingPage, Errors> {
+        self.api
+            .contest_ranking(&self.detail.contest.title_slug, page)
+            .await
+    }
+
+    pub async fn participants(&self) -> Result<Vec<Participant>, Errors> {
+        let mut participants = Vec::new();
+        let mut page = 1;
+        loop {
+            let ranking = self.ranking(page).await?;
+            if ranking.total_rank.is_empty() {
+                return Ok(participants);
+            }
+
+            for entry in &ranking.total_rank {
+                let contest_ranking = self
+                    .api
+                    .find_profile(&entry.username)
+                    .contest_ranking()
+                    .await?;
+                participants.push(Participant::from_ranking(entry, contest_ranking.as_ref()));
+            }
+
+            if participants.len() >= ranking.user_num as usize {
+                return Ok(participants);
+            }
+            page += 1;
+        }
+    }
+
+    pub async fn predict(&self) -> Result<Vec<RatingPrediction>, Errors> {
+        Ok(predict_rating_changes(&self.participants().await?))
+    }
+
+    pub async fn load_problem(&self, index: usize) -> Result<Problem, Errors> {
+        let question = self.detail.questions.get(index).ok_or_else(|| {
+            Errors::ApiError(format!("Contest has no problem with index `{}`", index))
+        })?;
+        self.api.set_problem_by_slug(&question.title_slug).await
+    }
+
+    pub async fn load_problems(&self) -> Result<Vec<Problem>, Errors> {
+        let mut problems = Vec::new();
+        for question in &self.detail.questions {
+            problems.push(self.api.set_problem_by_slug(&question.title_slug).await?);
+        }
+        Ok(problems)
+    }
+}
+
+impl ContestRankingPage {
+    pub fn leaderboard(&self) -> Vec<LeaderboardRow> {
+        self.total_rank
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let mut problems = self
+                    .submissions
+                    .get(index)
+                    .map(|submissions| {
+                        submissions
+                            .values()
+                            .map(|submission| ProblemFinish {
+                                question_id: submission.question_id,
+                                finished_at: submission.date,
+                                fail_count: submission.fail_count,
+                                penalty_seconds: i64::from(submission.fail_count)
+                                    * WRONG_SUBMISSION_PENALTY,
+                            })
+                            .collect::<Vec<ProblemFinish>>()
+                    })
+                    .unwrap_or_default();
+                problems.sort_by_key(|problem| {
+                    self.questions
+                        .iter()
+                        .position(|question| question.question_id == problem.question_id)
+                });
+
+                LeaderboardRow {
+                    rank: entry.rank,
+                    username: entry.username.clone(),
+                    score: entry.score,
+                    finish_time: entry.finish_time,
+                    problems,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Participant {
+    pub fn from_ranking(
+        entry: &RankingEntry,
+        contest_ranking: Option<&UserContestRanking>,
+    ) -> Participant {
+        Participant {
+            username: entry.username.clone(),
+            rank: entry.rank,
+            rating: contest_ranking.map_or(INITIAL_RATING, |ranking| ranking.rating),
+            attended_contests: contest_ranking.map_or(0, |ranking| ranking.attendedContestsCount),
+        }
+    }
+}
+
+impl ProblemFinish {
+    pub fn elapsed(&self, start_time: i64) -> i64 {
+        self.finished_at - start_time
+    }
+}
+
+pub fn predict_rating_changes(participants: &[Participant]) -> Vec<RatingPrediction> {
+    let ratings = participants
+        .iter()
+        .map(|participant| clamp_rating(participant.rating))
+        .collect::<Vec<f64>>();
+    let Some(max_rating) = ratings.iter().copied().reduce(f64::max) else {
+        return Vec::new();
+    };
+
+    let grid_size =
+        (max_rating.max(INITIAL_RATING) as usize + RATING_GRID_MARGIN) * RATING_GRID_STEPS;
+    let expected_ranks = (0..=grid_size)
+        .map(|index| {
+            let rating = index as f64 / RATING_GRID_STEPS as f64;
+            1.0 + ratings
+                .iter()
+                .map(|other| win_probability(*other, rating))
+                .sum::<f64>()
+        })
+        .collect::<Vec<f64>>();
+
+    participants
+        .iter()
+        .zip(ratings.iter().copied())
+        .map(|(participant, old_rating)| {
+            let expected_rank = |rating: f64| {
+                let position = (rating.max(0.0) * RATING_GRID_STEPS as f64).min(grid_size as f64);
+                let lower = position.floor() as usize;
+                let upper = (lower + 1).min(grid_size);
+                let fraction = position - lower as f64;
+                let all =
+                    expected_ranks[lower] * (1.0 - fraction) + expected_ranks[upper] * fraction;
+                all - win_probability(old_rating, rating)
+            };
+
+            let seed = expected_rank(old_rating);
+            let target = (seed * f64::from(participant.rank.max(1))).sqrt();
+
+            let (mut low, mut high) = (0.0, grid_size as f64 / RATING_GRID_STEPS as f64);
+            for _ in 0..RATING_SEARCH_ITERATIONS {
+                let middle = (low + high) / 2.0;
+                if expected_rank(middle) > target {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+
+            let delta = (low - old_rating) * adjustment_factor(participant.attended_contests);
+            RatingPrediction {
+                username: participant.username.clone(),
+                old_rating,
+                new_rating: old_rating + delta,
+                delta,
+            }
+        })
+        .collect()
+}
+
+const RATING_GRID_STEPS: usize = 2;
+const RATING_GRID_MARGIN: usize = 1000;
+const RATING_SEARCH_ITERATIONS: u32 = 40;
+const MAX_RATING: f64 = 5000.0;
+const MAX_ADJUSTMENT_TERMS: u32 = 200;
+
+fn clamp_rating(rating: f64) -> f64 {
+    if rating.is_nan() {
+        INITIAL_RATING
+    } else {
+        rating.clamp(0.0, MAX_RATING)
+    }
+}
+
+fn win_probability(rating: f64, against: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((against - rating) / 400.0))
+}
+
+fn adjustment_factor(attended_contests: u32) -> f64 {
+    let series = (0..=attended_contests.min(MAX_ADJUSTMENT_TERMS))
+        .map(|index| (5.0f64 / 7.0).powi(index as i32))
+        .sum::<f64>();
+    1.0 / (1.0 + series)
+}
+
+pub(crate) fn contest_results(history: Vec<UserContestHistoryNode>) -> Vec<ContestResult> {
+    let mut previous_rating = INITIAL_RATING;
+    history
+        .into_iter()
+        .filter(|node| node.attended)
+        .map(|node| {
+            let rating_change = node.rating - previous_rating;
+            previous_rating = node.rating;
+            ContestResult {
+                series: series_from_title(&node.contest.title),
+                title: node.contest.title,
+                start_time: node.contest.startTime,
+                rank: node.ranking,
+                rating: node.rating,
+                rating_change,
+                problems_solved: node.problemsSolved,
+                total_problems: node.totalProblems,
+                finish_time_in_seconds: node.finishTimeInSeconds,
+            }
+        })
+        .collect()
+}
+
+fn series_from_title(title: &str) -> ContestSeries {
+    ContestSeries::from_slug(&title.to_ascii_lowercase().replace(' ', "-"))
+}
+
+impl UpcomingContest {
+    pub fn series(&self) -> ContestSeries {
+        ContestSeries::from_slug(&self.titleSlug)
+    }
+}
+
+impl PastContest {
+    pub fn series(&self) -> ContestSeries {
+        ContestSeries::from_slug(&self.titleSlug)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        adjustment_factor, predict_rating_changes, Participant, INITIAL_RATING, MAX_RATING,
+    };
+    use crate::resources::contest::{RankingEntry, UserContestRanking};
+
+    fn participant(username: &str, rank: u32, rating: f64, attended_contests: u32) -> Participant {
+        Participant {
+            username: String::from(username),
+            rank,
+            rating,
+            attended_contests,
+        }
+    }
+
+    fn deltas(participants: &[Participant]) -> Vec<f64> {
+        predict_rating_changes(participants)
+            .into_iter()
+            .map(|prediction| prediction.delta)
+            .collect()
+    }
+
+    #[test]
+    fn adjustment_factor_matches_published_values() {
+        // LeetCode's rating announcement gives f(0) = 1/2 and f(k) -> 2/9.
+        assert!((adjustment_factor(0) - 0.5).abs() < 1e-12);
+        assert!((adjustment_factor(1) - 7.0 / 19.0).abs() < 1e-12);
+        assert!((adjustment_factor(500) - 2.0 / 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn two_first_timers() {
+        // The winner's expected rank 1.5 and actual rank 1 give a target rank of
+        // sqrt(1.5). Solving 1 + 1 / (1 + 10^((R - 1500) / 400)) = sqrt(1.5) gives
+        // R = 1715.10. Half of that goes into the first rating change.
+        let predictions = predict_rating_changes(&[
+            participant("winner", 1, 1500.0, 0),
+            participant("loser", 2, 1500.0, 0),
+        ]);
+
+        assert!((predictions[0].delta - 107.55).abs() < 0.05);
+        assert!((predictions[0].new_rating - 1607.55).abs() < 0.05);
+        assert!((predictions[1].delta + 87.29).abs() < 0.05);
+    }
+
+    #[test]
+    fn equal_ratings_are_symmetric() {
+        let field = (1..=5)
+            .map(|rank| participant(&rank.to_string(), rank, 1800.0, 10))
+            .collect::<Vec<Participant>>();
+        let forward = deltas(&field);
+
+        assert!(forward[2].abs() < 0.05);
+        assert!(forward[0] > 0.0 && forward[1] > 0.0);
+        assert!(forward[3] < 0.0 && forward[4] < 0.0);
+
+        let mut reversed = field.clone();
+        reversed.reverse();
+        let backward = deltas(&reversed);
+        for (forward, backward) in forward.iter().zip(backward.iter().rev()) {
+            assert!((forward - backward).abs() < 1e-9);
+        }
+
+        let tied = deltas(&[
+            participant("a", 1, 1600.0, 3),
+            participant("b", 1, 1600.0, 3),
+            participant("c", 3, 1400.0, 3),
+        ]);
+        assert!((tied[0] - tied[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn better_rank_never_gets_smaller_delta() {
+        let ratings = [
+            2400.0, 2100.0, 1900.0, 1750.0, 1600.0, 1500.0, 1450.0, 1300.0,
+        ];
+        let mut previous = f64::INFINITY;
+        for rank in 1..=ratings.len() as u32 {
+            let field = ratings
+                .iter()
+                .enumerate()
+                .map(|(index, rating)| {
+                    let rank = if index == 0 { rank } else { index as u32 + 1 };
+                    participant(&index.to_string(), rank, *rating, 20)
+                })
+                .collect::<Vec<Participant>>();
+            let delta = deltas(&field)[0];
+            assert!(delta <= previous);
+            previous = delta;
+        }
+
+        let field = (1..=8)
+            .map(|rank| participant(&rank.to_string(), rank, 1500.0, 0))
+            .collect::<Vec<Participant>>();
+        let deltas = deltas(&field);
+        assert!(deltas.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn out_of_range_ratings_are_clamped() {
+        let predictions = predict_rating_changes(&[
+            participant("infinite", 1, f64::INFINITY, 5),
+            participant("nan", 2, f64::NAN, u32::MAX),
+            participant("huge", 3, 1e300, 5),
+            participant("negative", 4, f64::NEG_INFINITY, 5),
+        ]);
+
+        assert_eq!(predictions[0].old_rating, MAX_RATING);
+        assert_eq!(predictions[1].old_rating, INITIAL_RATING);
+        assert_eq!(predictions[2].old_rating, MAX_RATING);
+        assert_eq!(predictions[3].old_rating, 0.0);
+        assert!(predictions
+            .iter()
+            .all(|prediction| prediction.new_rating.is_finite() && prediction.delta.is_finite()));
+    }
+
+    #[test]
+    fn participant_from_ranking() {
+        let entry = RankingEntry {
+            username: String::from("alice"),
+            user_slug: None,
+            real_name: None,
+            country_name: None,
+            data_region: Some(String::from("US")),
+            rank: 7,
+            score: 18,
+            finish_time: 1_700_000_000,
+        };
+        let contest_ranking = UserContestRanking {
+            attendedContestsCount: 12,
+            rating: 1850.5,
+            globalRanking: 1000,
+            totalParticipants: 500_000,
+            topPercentage: 1.5,
+            badge: None,
+        };
+
+        let rated = Participant::from_ranking(&entry, Some(&contest_ranking));
+        assert_eq!(rated.username, "alice");
+        assert_eq!(rated.rank, 7);
+        assert_eq!(rated.rating, 1850.5);
+        assert_eq!(rated.attended_contests, 12);
+
+        let newcomer = Participant::from_ranking(&entry, None);
+        assert_eq!(newcomer.rating, INITIAL_RATING);
+        assert_eq!(newcomer.attended_contests, 0);
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use tokio::io;
 
 #[derive(thiserror::Error, Debug)]
@@ -10,10 +12,24 @@ pub enum Errors {
     ApiError(String),
     #[error("Sendrror(`{0}`)")]
     SendError(String),
+    #[error("IoError(`{0}`)")]
+    IoError(#[from] io::Error),
+    #[error("CsvError(`{0}`)")]
+    CsvError(#[from] csv::Error),
+    #[error("JudgeTimeout(Judge did not finish within `{0:?}`)")]
+    JudgeTimeout(Duration),
+    #[error(
+        "PartialListUpdate(Applied questions `{applied:?}`, then failed on `{failed}` with `{source}`)"
+    )]
+    PartialListUpdate {
+        applied: Vec<String>,
+        failed: String,
+        source: Box<Errors>,
+    },
 }
 
 impl std::convert::From<Errors> for io::Error {
     fn from(error: Errors) -> Self {
-        io::Error::new(io::ErrorKind::Other, error.to_string())
+        io::Error::other(error.to_string())
     }
 }
@@ -0,0 +1,214 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    assets,
+    error::Errors,
+    problem_actions::Problem,
+    problem_build::Status,
+    render,
+    resources::{subm_details::SubmissionDetailsNode, subm_send::SubmissionId},
+    submissions::{SubmStatus, SubmissionFilter},
+    ProgrammingLanguage, UserApi,
+};
+
+const SOLVED_PAGE_LIMIT: u32 = 100;
+
+#[derive(Debug)]
+pub struct SolutionExporter<'a> {
+    pub(crate) api: &'a UserApi,
+    pub(crate) dir: PathBuf,
+    pub(crate) manifest_name: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ExportManifest {
+    pub problems: HashMap<String, HashMap<String, SubmissionId>>,
+}
+
+#[derive(Debug, Default)]
+pub struct ExportReport {
+    pub written: Vec<PathBuf>,
+    pub skipped: u32,
+    pub failed: Vec<(String, Errors)>,
+    pub readme_failed: Vec<(String, Errors)>,
+}
+
+impl<'a> SolutionExporter<'a> {
+    pub fn set_manifest_name(mut self, manifest_name: &str) -> SolutionExporter<'a> {
+        self.manifest_name = String::from(manifest_name);
+        self
+    }
+
+    pub async fn export(self) -> Result<ExportReport, Errors> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+
+        let mut manifest = self.load_manifest().await?;
+        let mut report = ExportReport::default();
+
+        for title_slug in self.solved_problems().await? {
+            if let Err(err) = self
+                .export_problem(&title_slug, &mut manifest, &mut report)
+                .await
+            {
+                report.failed.push((title_slug, err));
+            }
+            self.save_manifest(&manifest).await?;
+        }
+
+        Ok(report)
+    }
+
+    async fn solved_problems(&self) -> Result<Vec<String>, Errors> {
+        let mut title_slugs = Vec::new();
+        loop {
+            let page = self
+                .api
+                .problem_builder()
+                .set_status(Status::Solved)
+                .set_note_limit(SOLVED_PAGE_LIMIT)
+                .set_skip(title_slugs.len() as u32)
+                .build()
+                .await?
+                .data
+                .problemsetQuestionList;
+
+            let page_len = page.questions.len();
+            title_slugs.extend(
+                page.questions
+                    .into_iter()
+                    .map(|question| question.titleSlug),
+            );
+
+            let exhausted = page
+                .total
+                .is_some_and(|total| title_slugs.len() as u32 >= total);
+            if page_len == 0 || exhausted {
+                return Ok(title_slugs);
+            }
+        }
+    }
+
+    async fn export_problem(
+        &self,
+        title_slug: &str,
+        manifest: &mut ExportManifest,
+        report: &mut ExportReport,
+    ) -> Result<(), Errors> {
+        let problem = self.api.set_problem_by_slug(title_slug).await?;
+
+        let mut latest = Vec::new();
+        let mut seen_langs = HashSet::new();
+        let mut history = Box::pin(problem.submission_history(
+            SubmissionFilter::default().set_statuses(vec![SubmStatus::Accepted]),
+        ));
+        while let Some(submission) = history.next().await {
+            let submission = submission?;
+            if seen_langs.insert(submission.lang.clone()) {
+                latest.push((submission.lang, submission.id));
+            }
+        }
+
+        let exported = manifest
+            .problems
+            .entry(String::from(title_slug))
+            .or_default();
+
+        let problem_dir = self.dir.join(format!(
+            "{}-{}",
+            problem.full_data.data.question.questionFrontendId, title_slug
+        ));
+
+        let mut used_names = HashSet::new();
+        let mut written_any = false;
+        for (lang, id) in latest {
+            let file_name = solution_file_name(&lang, &mut used_names);
+            if exported.get(&lang) == Some(&id) && problem_dir.join(&file_name).exists() {
+                report.skipped += 1;
+                continue;
+            }
+
+            let details = self.api.submission_details(id).await?;
+
+            tokio::fs::create_dir_all(&problem_dir).await?;
+            let path = problem_dir.join(&file_name);
+            tokio::fs::write(&path, solution_content(&details)).await?;
+
+            exported.insert(lang, id);
+            report.written.push(path);
+            written_any = true;
+        }
+
+        if written_any {
+            self.save_manifest(manifest).await?;
+            if let Err(err) = write_readme(&problem, &problem_dir).await {
+                report.readme_failed.push((String::from(title_slug), err));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn load_manifest(&self) -> Result<ExportManifest, Errors> {
+        let path = self.dir.join(&self.manifest_name);
+        if !path.exists() {
+            return Ok(ExportManifest::default());
+        }
+
+        let manifest = tokio::fs::read_to_string(path).await?;
+        Ok(serde_json::from_str::<ExportManifest>(&manifest)?)
+    }
+
+    async fn save_manifest(&self, manifest: &ExportManifest) -> Result<(), Errors> {
+        let manifest = serde_json::to_string_pretty(manifest)?;
+        tokio::fs::write(self.dir.join(&self.manifest_name), manifest).await?;
+        Ok(())
+    }
+}
+
+async fn write_readme(problem: &Problem, problem_dir: &std::path::Path) -> Result<(), Errors> {
+    let question = &problem.full_data.data.question;
+    let content = assets::download_images(
+        question.content.as_deref().unwrap_or_default(),
+        &problem_dir.join("images"),
+    )
+    .await?;
+    let readme = format!(
+        "# {}. {}\n\n{}\n\n{}\n",
+        question.questionFrontendId,
+        question.title,
+        question.difficulty,
+        render::html_to_markdown(&content)
+    );
+
+    tokio::fs::write(problem_dir.join("README.md"), readme).await?;
+    Ok(())
+}
+
+fn solution_content(details: &SubmissionDetailsNode) -> String {
+    let mut code = details.code.clone();
+    if !code.ends_with('\n') {
+        code.push('\n');
+    }
+    code
+}
+
+fn solution_file_name(lang: &str, used_names: &mut HashSet<String>) -> String {
+    let extension = ProgrammingLanguage::from_slug(lang)
+        .map(|lang| lang.extension())
+        .unwrap_or("txt");
+
+    let file_name = format!("solution.{}", extension);
+    if used_names.insert(file_name.clone()) {
+        return file_name;
+    }
+
+    let file_name = format!("solution_{}.{}", lang, extension);
+    used_names.insert(file_name.clone());
+    file_name
+}
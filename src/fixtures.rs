@@ -0,0 +1,159 @@
+use serde_json::json;
+
+use crate::{judge::PollConfig, problem_actions::Problem};
+
+fn example(input: &str, output: &str) -> String {
+    format!(
+        "<p><strong class=\"example\">Example:</strong></p>\n<pre>\n<strong>Input:</strong> {}\n<strong>Output:</strong> {}\n</pre>\n",
+        input, output
+    )
+}
+
+fn problem(
+    id: &str,
+    title: &str,
+    title_slug: &str,
+    content: String,
+    example_testcases: &str,
+    meta_data: &str,
+    snippets: &[(&str, &str)],
+) -> Problem {
+    let snippets = snippets
+        .iter()
+        .map(|(lang_slug, code)| json!({"lang": lang_slug, "langSlug": lang_slug, "code": code}))
+        .collect::<Vec<_>>();
+    let full_data = json!({"data": {"question": {
+        "questionId": id,
+        "questionFrontendId": id,
+        "boundTopicId": null,
+        "title": title,
+        "titleSlug": title_slug,
+        "content": content,
+        "translatedTitle": null,
+        "translatedContent": null,
+        "isPaidOnly": false,
+        "canSeeQuestion": true,
+        "difficulty": "Easy",
+        "likes": 0,
+        "dislikes": 0,
+        "isLiked": null,
+        "similarQuestions": "[]",
+        "exampleTestcases": example_testcases,
+        "categoryTitle": "Algorithms",
+        "contributors": [],
+        "topicTags": [],
+        "companyTagStats": null,
+        "codeSnippets": snippets,
+        "stats": "{}",
+        "hints": [],
+        "solution": null,
+        "status": null,
+        "sampleTestCase": "",
+        "metaData": meta_data,
+        "judgerAvailable": true,
+        "judgeType": "large",
+        "mysqlSchemas": [],
+        "enableRunCode": true,
+        "enableTestMode": false,
+        "enableDebugger": true,
+        "envInfo": "{}"
+    }}});
+
+    Problem {
+        client: reqwest::Client::new(),
+        task_search_name: String::from(title_slug),
+        full_data: serde_json::from_value(full_data).unwrap(),
+        poll_config: PollConfig::default(),
+    }
+}
+
+pub(crate) fn two_sum() -> Problem {
+    problem(
+        "1",
+        "Two Sum",
+        "two-sum",
+        format!(
+            "<p>Return indices of the two numbers that add up to <code>target</code>.</p>\n{}{}",
+            example("nums = [2,7,11,15], target = 9", "[0,1]"),
+            example("nums = [3,2,4], target = 6", "[1,2]")
+        ),
+        "[2,7,11,15]\n9\n[3,2,4]\n6",
+        r#"{"name":"twoSum","params":[{"name":"nums","type":"integer[]"},{"name":"target","type":"integer"}],"return":{"type":"integer[]","size":2}}"#,
+        &[
+            ("rust", "impl Solution {\n    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n        \n    }\n}"),
+            ("cpp", "class Solution {\npublic:\n    vector<int> twoSum(vector<int>& nums, int target) {\n        \n    }\n};"),
+            ("java", "class Solution {\n    public int[] twoSum(int[] nums, int target) {\n        \n    }\n}"),
+            ("golang", "func twoSum(nums []int, target int) []int {\n    \n}"),
+            ("python3", "class Solution:\n    def twoSum(self, nums: List[int], target: int) -> List[int]:\n        "),
+            ("typescript", "function twoSum(nums: number[], target: number): number[] {\n    \n};"),
+        ],
+    )
+}
+
+pub(crate) fn merge_two_lists() -> Problem {
+    problem(
+        "21",
+        "Merge Two Sorted Lists",
+        "merge-two-sorted-lists",
+        example("list1 = [1,2,4], list2 = [1,3,4]", "[1,1,2,3,4,4]"),
+        "[1,2,4]\n[1,3,4]",
+        r#"{"name":"mergeTwoLists","params":[{"name":"list1","type":"ListNode"},{"name":"list2","type":"ListNode"}],"return":{"type":"ListNode"}}"#,
+        &[
+            ("rust", "// Definition for singly-linked list.\n// #[derive(PartialEq, Eq, Clone, Debug)]\n// pub struct ListNode {\n//   pub val: i32,\n//   pub next: Option<Box<ListNode>>\n// }\nimpl Solution {\n    pub fn merge_two_lists(list1: Option<Box<ListNode>>, list2: Option<Box<ListNode>>) -> Option<Box<ListNode>> {\n        \n    }\n}"),
+            ("cpp", "/**\n * Definition for singly-linked list.\n * struct ListNode {\n *     int val;\n *     ListNode *next;\n * };\n */\nclass Solution {\npublic:\n    ListNode* mergeTwoLists(ListNode* list1, ListNode* list2) {\n        \n    }\n};"),
+            ("java", "/**\n * Definition for singly-linked list.\n * public class ListNode {\n *     int val;\n *     ListNode next;\n * }\n */\nclass Solution {\n    public ListNode mergeTwoLists(ListNode list1, ListNode list2) {\n        \n    }\n}"),
+            ("typescript", "/**\n * Definition for singly-linked list.\n * class ListNode {\n *     val: number\n *     next: ListNode | null\n *     constructor(val?: number, next?: ListNode | null) {\n *         this.val = (val===undefined ? 0 : val)\n *         this.next = (next===undefined ? null : next)\n *     }\n * }\n */\n\nfunction mergeTwoLists(list1: ListNode | null, list2: ListNode | null): ListNode | null {\n    \n};"),
+        ],
+    )
+}
+
+pub(crate) fn invert_tree() -> Problem {
+    problem(
+        "226",
+        "Invert Binary Tree",
+        "invert-binary-tree",
+        example("root = [4,2,7,1,3,6,9]", "[4,7,2,9,6,3,1]"),
+        "[4,2,7,1,3,6,9]",
+        r#"{"name":"invertTree","params":[{"name":"root","type":"TreeNode"}],"return":{"type":"TreeNode"}}"#,
+        &[
+            ("rust", "// Definition for a binary tree node.\n// #[derive(Debug, PartialEq, Eq)]\n// pub struct TreeNode {\n//   pub val: i32,\n//   pub left: Option<Rc<RefCell<TreeNode>>>,\n//   pub right: Option<Rc<RefCell<TreeNode>>>,\n// }\nuse std::rc::Rc;\nuse std::cell::RefCell;\nimpl Solution {\n    pub fn invert_tree(root: Option<Rc<RefCell<TreeNode>>>) -> Option<Rc<RefCell<TreeNode>>> {\n        \n    }\n}"),
+            ("cpp", "class Solution {\npublic:\n    TreeNode* invertTree(TreeNode* root) {\n        \n    }\n};"),
+        ],
+    )
+}
+
+pub(crate) fn min_stack() -> Problem {
+    problem(
+        "155",
+        "Min Stack",
+        "min-stack",
+        example(
+            "[\"MinStack\",\"push\",\"push\",\"getMin\",\"pop\",\"top\"] [[],[-2],[0],[],[],[]]",
+            "[null,null,null,-2,null,-2]",
+        ),
+        "[\"MinStack\",\"push\",\"push\",\"getMin\",\"pop\",\"top\"]\n[[],[-2],[0],[],[],[]]",
+        r#"{"classname":"MinStack","constructor":{"params":[]},"methods":[{"params":[{"type":"integer","name":"val"}],"name":"push","return":{"type":"void"}},{"params":[],"name":"pop","return":{"type":"void"}},{"params":[],"name":"top","return":{"type":"integer"}},{"params":[],"name":"getMin","return":{"type":"integer"}}],"systemdesign":true}"#,
+        &[
+            ("rust", "struct MinStack {\n\n}\n\n\nimpl MinStack {\n\n    fn new() -> Self {\n        \n    }\n    \n    fn push(&self, val: i32) {\n        \n    }\n    \n    fn pop(&self) {\n        \n    }\n    \n    fn top(&self) -> i32 {\n        \n    }\n    \n    fn get_min(&self) -> i32 {\n        \n    }\n}"),
+            ("cpp", "class MinStack {\npublic:\n    MinStack() {\n        \n    }\n    \n    void push(int val) {\n        \n    }\n};"),
+        ],
+    )
+}
+
+pub(crate) fn rotate_without_output() -> Problem {
+    problem(
+        "189",
+        "Rotate Array",
+        "rotate-array",
+        example("nums = [1,2,3], k = 1", "[3,1,2]"),
+        "[1,2,3]\n1",
+        r#"{"name":"rotate","params":[{"name":"nums","type":"integer[]"},{"name":"k","type":"integer"}],"return":{"type":"void"}}"#,
+        &[
+            ("rust", "impl Solution {\n    pub fn rotate(nums: &mut Vec<i32>, k: i32) {\n        \n    }\n}"),
+            ("cpp", "class Solution {\npublic:\n    void rotate(vector<int>& nums, int k) {\n        \n    }\n};"),
+            ("java", "class Solution {\n    public void rotate(int[] nums, int k) {\n        \n    }\n}"),
+            ("golang", "func rotate(nums []int, k int)  {\n    \n}"),
+            ("typescript", "/**\n Do not return anything, modify nums in-place instead.\n */\nfunction rotate(nums: number[], k: number): void {\n    \n};"),
+        ],
+    )
+}
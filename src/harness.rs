@@ -0,0 +1,1354 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{
+    error::Errors,
+    problem_actions::Problem,
+    resources::meta_data::{MetaData, Param},
+    scaffold, ProgrammingLanguage,
+};
+
+#[derive(Debug, Clone)]
+pub struct TestHarness {
+    pub lang: ProgrammingLanguage,
+    pub file_name: String,
+    pub source: String,
+}
+
+impl TestHarness {
+    pub async fn write(&self, dir: &str) -> Result<(), Errors> {
+        let dir = Path::new(dir);
+        tokio::fs::create_dir_all(dir).await?;
+        tokio::fs::write(dir.join(&self.file_name), &self.source).await?;
+        Ok(())
+    }
+}
+
+struct Case {
+    args: Vec<Value>,
+    expected: Value,
+}
+
+struct Context {
+    header: Vec<String>,
+    snippet: String,
+    method: String,
+    params: Vec<Param>,
+    ret: String,
+    mutated: Option<usize>,
+    cases: Vec<Case>,
+}
+
+impl Context {
+    fn result_type(&self) -> &str {
+        match self.mutated {
+            Some(index) if self.ret == "void" => &self.params[index].type_,
+            _ => &self.ret,
+        }
+    }
+
+    fn checks_result(&self) -> bool {
+        self.ret != "void" || self.mutated.is_some()
+    }
+
+    fn is_double(&self) -> bool {
+        self.result_type() == "double"
+    }
+
+    fn expected(&self, case: &Case) -> String {
+        serde_json::to_string(&case.expected).unwrap_or_default()
+    }
+}
+
+pub(crate) fn test_harness(
+    problem: &Problem,
+    lang: ProgrammingLanguage,
+) -> Result<TestHarness, Errors> {
+    if lang == ProgrammingLanguage::Rust {
+        let scaffold = problem.rust_scaffold()?;
+        return Ok(TestHarness {
+            lang,
+            file_name: String::from("lib.rs"),
+            source: scaffold.source,
+        });
+    }
+
+    let (file_name, run) = match lang {
+        ProgrammingLanguage::Python3 => ("solution.py", "python3 solution.py"),
+        ProgrammingLanguage::CPP => (
+            "solution.cpp",
+            "g++ -std=c++17 -O2 solution.cpp -o solution && ./solution",
+        ),
+        ProgrammingLanguage::Java => ("Main.java", "javac Main.java && java Main"),
+        ProgrammingLanguage::Go => ("main.go", "go run main.go"),
+        ProgrammingLanguage::TypeScript => ("solution.ts", "npx tsx solution.ts"),
+        _ => {
+            return Err(Errors::ApiError(format!(
+                "Test harness is not available for `{}`",
+                lang
+            )))
+        }
+    };
+
+    let snippet = problem.snippet(lang).ok_or_else(|| {
+        Errors::ApiError(format!("Lang `{}` is not supported for this problem", lang))
+    })?;
+    let meta_data = problem.meta_data()?;
+    if meta_data.is_system_design() {
+        return Err(Errors::ApiError(
+            "Test harness is not available for design problems".into(),
+        ));
+    }
+
+    let question = &problem.full_data.data.question;
+    let comment = lang.line_comment();
+    let ctx = Context {
+        header: vec![
+            format!(
+                "{} {}. {}",
+                comment, question.questionFrontendId, question.title
+            ),
+            format!(
+                "{} https://leetcode.com/problems/{}/",
+                comment, question.titleSlug
+            ),
+            format!("{} Run: {}", comment, run),
+        ],
+        snippet,
+        method: meta_data.name.clone().unwrap_or_default(),
+        params: meta_data.params.clone().unwrap_or_default(),
+        ret: meta_data
+            .return_
+            .as_ref()
+            .map_or(String::from("void"), |ret| ret.type_.clone()),
+        mutated: meta_data
+            .output
+            .as_ref()
+            .and_then(|output| output.paramindex),
+        cases: collect_cases(problem, &meta_data)?,
+    };
+
+    let source = match lang {
+        ProgrammingLanguage::Python3 => python_harness(&ctx),
+        ProgrammingLanguage::CPP => cpp_harness(&ctx),
+        ProgrammingLanguage::Java => java_harness(&ctx),
+        ProgrammingLanguage::Go => go_harness(&ctx),
+        _ => typescript_harness(&ctx),
+    };
+
+    Ok(TestHarness {
+        lang,
+        file_name: String::from(file_name),
+        source,
+    })
+}
+
+fn collect_cases(problem: &Problem, meta_data: &MetaData) -> Result<Vec<Case>, Errors> {
+    let params = meta_data.params.as_ref().map_or(0, Vec::len);
+    let outputs = problem.description_sections().examples;
+
+    Ok(problem
+        .example_inputs()?
+        .iter()
+        .zip(outputs)
+        .filter_map(|(input, example)| {
+            let args = input
+                .iter()
+                .map(|raw| scaffold::parse_value(raw))
+                .collect::<Option<Vec<Value>>>()?;
+            let expected = scaffold::parse_value(&example.output)?;
+            (args.len() == params).then_some(Case { args, expected })
+        })
+        .collect())
+}
+
+fn element_type(ty: &str) -> Option<&str> {
+    ty.strip_suffix("[]").or_else(|| {
+        ty.strip_prefix("list<")
+            .and_then(|inner| inner.strip_suffix('>'))
+    })
+}
+
+fn json_string(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .and_then(|text| serde_json::to_string(text).ok())
+}
+
+fn char_literal(value: &Value) -> Option<String> {
+    let text = value.as_str()?;
+    let mut chars = text.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(match ch {
+        '\'' => String::from("'\\''"),
+        '\\' => String::from("'\\\\'"),
+        ch => format!("'{}'", ch),
+    })
+}
+
+fn int_list(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|val| val.as_i64().map(|val| val.to_string()))
+        .collect()
+}
+
+fn tree_list(value: &Value, null: &str) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|val| match val {
+            Value::Null => Some(String::from(null)),
+            val => val.as_i64().map(|val| val.to_string()),
+        })
+        .collect()
+}
+
+fn fill_braced_bodies(snippet: &str, placeholder: &str) -> String {
+    let lines = snippet.lines().collect::<Vec<&str>>();
+    let mut filled = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        filled.push(String::from(line));
+        i += 1;
+
+        let trimmed = line.trim();
+        let is_method = trimmed.ends_with('{')
+            && trimmed.contains('(')
+            && !trimmed.starts_with("//")
+            && !trimmed.starts_with('*');
+        if !is_method {
+            continue;
+        }
+
+        let mut j = i;
+        while j < lines.len() && lines[j].trim().is_empty() {
+            j += 1;
+        }
+        if j < lines.len() && lines[j].trim().starts_with('}') {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            filled.push(format!("{}    {}", indent, placeholder));
+            i = j;
+        }
+    }
+
+    filled.join("\n")
+}
+
+fn python_literal(value: &Value, ty: &str) -> Option<String> {
+    match ty {
+        "ListNode" => Some(format!("list_node([{}])", int_list(value)?.join(", "))),
+        "TreeNode" => Some(format!(
+            "tree_node([{}])",
+            tree_list(value, "None")?.join(", ")
+        )),
+        _ => match value {
+            Value::Null => Some(String::from("None")),
+            Value::Bool(flag) => Some(String::from(if *flag { "True" } else { "False" })),
+            Value::Number(num) => Some(num.to_string()),
+            Value::String(_) => json_string(value),
+            Value::Array(values) => {
+                let inner = element_type(ty).unwrap_or_default();
+                let values = values
+                    .iter()
+                    .map(|val| python_literal(val, inner))
+                    .collect::<Option<Vec<String>>>()?;
+                Some(format!("[{}]", values.join(", ")))
+            }
+            Value::Object(_) => None,
+        },
+    }
+}
+
+fn fill_python_bodies(snippet: &str) -> String {
+    let lines = snippet.lines().collect::<Vec<&str>>();
+    let mut filled = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        filled.push(String::from(*line));
+        let trimmed = line.trim();
+        if !trimmed.starts_with("def ") || !trimmed.ends_with(':') {
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+        let has_body = lines[i + 1..]
+            .iter()
+            .find(|next| !next.trim().is_empty())
+            .is_some_and(|next| next.len() - next.trim_start().len() > indent);
+        if !has_body {
+            filled.push(format!("{}    raise NotImplementedError", &line[..indent]));
+        }
+    }
+
+    filled.join("\n")
+}
+
+const PYTHON_HELPERS: &str = r#"class ListNode:
+    def __init__(self, val=0, next=None):
+        self.val = val
+        self.next = next
+
+
+class TreeNode:
+    def __init__(self, val=0, left=None, right=None):
+        self.val = val
+        self.left = left
+        self.right = right
+
+
+def list_node(values):
+    head = None
+    for val in reversed(values):
+        head = ListNode(val, head)
+    return head
+
+
+def tree_node(values):
+    if not values or values[0] is None:
+        return None
+    root = TreeNode(values[0])
+    queue = collections.deque([root])
+    rest = iter(values[1:])
+    while queue:
+        node = queue.popleft()
+        for side in ("left", "right"):
+            val = next(rest, StopIteration)
+            if val is StopIteration:
+                return root
+            if val is not None:
+                child = TreeNode(val)
+                setattr(node, side, child)
+                queue.append(child)
+    return root
+
+
+def plain(value):
+    if isinstance(value, ListNode):
+        values = []
+        while value:
+            values.append(value.val)
+            value = value.next
+        return values
+    if isinstance(value, TreeNode):
+        values, queue = [], collections.deque([value])
+        while queue:
+            node = queue.popleft()
+            values.append(node.val if node else None)
+            if node:
+                queue.append(node.left)
+                queue.append(node.right)
+        while values and values[-1] is None:
+            values.pop()
+        return values
+    if isinstance(value, (list, tuple)):
+        return [plain(item) for item in value]
+    return value
+
+
+def serialize(value):
+    return json.dumps(plain(value), separators=(",", ":"), ensure_ascii=False)
+"#;
+
+fn python_harness(ctx: &Context) -> String {
+    let mut source = ctx.header.join("\n");
+    source.push_str("\n\nimport collections\nimport json\nimport math\nfrom typing import *\n\n\n");
+    source.push_str(PYTHON_HELPERS);
+    source.push_str("\n\n");
+    source.push_str(fill_python_bodies(&ctx.snippet).trim_end());
+    source.push_str("\n\n\nCASES = [\n");
+    for case in &ctx.cases {
+        let args = ctx
+            .params
+            .iter()
+            .zip(&case.args)
+            .map(|(param, arg)| python_literal(arg, &param.type_))
+            .collect::<Option<Vec<String>>>();
+        if let Some(args) = args {
+            source.push_str(&format!(
+                "    ([{}], {}),\n",
+                args.join(", "),
+                serde_json::to_string(&ctx.expected(case)).unwrap_or_default()
+            ));
+        }
+    }
+    source.push_str("]\n\n\ndef main():\n    failed = 0\n");
+    source.push_str("    for number, (args, expected) in enumerate(CASES, 1):\n");
+    source.push_str(&format!(
+        "        result = Solution().{}(*args)\n",
+        ctx.method
+    ));
+    if let (Some(index), "void") = (ctx.mutated, ctx.ret.as_str()) {
+        source.push_str(&format!("        result = args[{}]\n", index));
+    }
+    if ctx.is_double() {
+        source.push_str(
+            "        passed = math.isclose(result, json.loads(expected), abs_tol=1e-5)\n",
+        );
+    } else {
+        source.push_str("        passed = serialize(result) == expected\n");
+    }
+    source.push_str(
+        r#"        if passed:
+            print(f"Example {number}: OK")
+        else:
+            failed += 1
+            print(f"Example {number}: FAILED, expected {expected}, got {serialize(result)}")
+    print(f"{len(CASES) - failed}/{len(CASES)} examples passed")
+    return 1 if failed else 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
+"#,
+    );
+    source
+}
+
+fn cpp_type(ty: &str) -> Option<String> {
+    let ty = match ty {
+        "integer" => "int",
+        "long" => "long long",
+        "double" => "double",
+        "boolean" => "bool",
+        "string" => "string",
+        "character" => "char",
+        "ListNode" => "ListNode*",
+        "TreeNode" => "TreeNode*",
+        "void" => "void",
+        _ => {
+            return element_type(ty)
+                .and_then(cpp_type)
+                .map(|inner| format!("vector<{}>", inner))
+        }
+    };
+    Some(String::from(ty))
+}
+
+fn cpp_literal(value: &Value, ty: &str) -> Option<String> {
+    match ty {
+        "integer" => value.as_i64().map(|num| num.to_string()),
+        "long" => value.as_i64().map(|num| format!("{}LL", num)),
+        "double" => value.as_f64().map(|num| format!("{:?}", num)),
+        "boolean" => value.as_bool().map(|flag| flag.to_string()),
+        "string" => json_string(value),
+        "character" => char_literal(value),
+        "ListNode" => Some(format!("make_list({{{}}})", int_list(value)?.join(", "))),
+        "TreeNode" => Some(format!(
+            "make_tree({{{}}})",
+            tree_list(value, "nullopt")?.join(", ")
+        )),
+        _ => {
+            let inner = element_type(ty)?;
+            let values = value
+                .as_array()?
+                .iter()
+                .map(|val| cpp_literal(val, inner))
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("{{{}}}", values.join(", ")))
+        }
+    }
+}
+
+const CPP_HELPERS: &str = r#"struct ListNode {
+    int val;
+    ListNode *next;
+    ListNode() : val(0), next(nullptr) {}
+    ListNode(int x) : val(x), next(nullptr) {}
+    ListNode(int x, ListNode *next) : val(x), next(next) {}
+};
+
+struct TreeNode {
+    int val;
+    TreeNode *left;
+    TreeNode *right;
+    TreeNode() : val(0), left(nullptr), right(nullptr) {}
+    TreeNode(int x) : val(x), left(nullptr), right(nullptr) {}
+    TreeNode(int x, TreeNode *left, TreeNode *right) : val(x), left(left), right(right) {}
+};
+
+ListNode* make_list(const vector<int>& values) {
+    ListNode* head = nullptr;
+    for (auto it = values.rbegin(); it != values.rend(); ++it) head = new ListNode(*it, head);
+    return head;
+}
+
+TreeNode* make_tree(const vector<optional<int>>& values) {
+    if (values.empty() || !values[0]) return nullptr;
+    TreeNode* root = new TreeNode(*values[0]);
+    queue<TreeNode*> nodes;
+    nodes.push(root);
+    size_t i = 1;
+    while (!nodes.empty() && i < values.size()) {
+        TreeNode* node = nodes.front();
+        nodes.pop();
+        if (i < values.size() && values[i]) { node->left = new TreeNode(*values[i]); nodes.push(node->left); }
+        i++;
+        if (i < values.size() && values[i]) { node->right = new TreeNode(*values[i]); nodes.push(node->right); }
+        i++;
+    }
+    return root;
+}
+
+string serialize(int value) { return to_string(value); }
+string serialize(long long value) { return to_string(value); }
+string serialize(bool value) { return value ? "true" : "false"; }
+string serialize(double value) { ostringstream out; out << value; return out.str(); }
+string serialize(const string& value) {
+    string out = "\"";
+    for (char c : value) {
+        if (c == '"' || c == '\\') out += '\\';
+        out += c;
+    }
+    return out + "\"";
+}
+string serialize(char value) { return serialize(string(1, value)); }
+string serialize(ListNode* head) {
+    string out = "[";
+    for (ListNode* node = head; node; node = node->next) out += (node == head ? "" : ",") + to_string(node->val);
+    return out + "]";
+}
+string serialize(TreeNode* root) {
+    vector<string> values;
+    queue<TreeNode*> nodes;
+    nodes.push(root);
+    while (!nodes.empty()) {
+        TreeNode* node = nodes.front();
+        nodes.pop();
+        values.push_back(node ? to_string(node->val) : "null");
+        if (node) { nodes.push(node->left); nodes.push(node->right); }
+    }
+    while (!values.empty() && values.back() == "null") values.pop_back();
+    string out = "[";
+    for (size_t i = 0; i < values.size(); i++) out += (i ? "," : "") + values[i];
+    return out + "]";
+}
+template <class T>
+string serialize(const vector<T>& values) {
+    string out = "[";
+    for (size_t i = 0; i < values.size(); i++) out += (i ? "," : "") + serialize((T)values[i]);
+    return out + "]";
+}
+"#;
+
+fn cpp_harness(ctx: &Context) -> String {
+    let mut source = ctx.header.join("\n");
+    source.push_str("\n\n#include <bits/stdc++.h>\nusing namespace std;\n\n");
+    source.push_str(CPP_HELPERS);
+    source.push('\n');
+    source.push_str(
+        fill_braced_bodies(&ctx.snippet, "throw runtime_error(\"not implemented\");").trim_end(),
+    );
+    source.push_str("\n\nint main() {\n    int failed = 0;\n    int total = 0;\n");
+
+    for (number, case) in ctx.cases.iter().enumerate() {
+        let mut block = String::from("    {\n        total++;\n");
+        let mut names = Vec::new();
+        let mut ok = true;
+        for (param, arg) in ctx.params.iter().zip(&case.args) {
+            match (cpp_type(&param.type_), cpp_literal(arg, &param.type_)) {
+                (Some(ty), Some(literal)) => {
+                    block.push_str(&format!("        {} {} = {};\n", ty, param.name, literal));
+                    names.push(param.name.clone());
+                }
+                _ => ok = false,
+            }
+        }
+        if !ok {
+            continue;
+        }
+
+        let call = format!("Solution().{}({})", ctx.method, names.join(", "));
+        if !ctx.checks_result() {
+            block.push_str(&format!(
+                "        {};\n        cout << \"Example {}: ran, no result to compare\" << endl;\n    }}\n",
+                call,
+                number + 1
+            ));
+            source.push_str(&block);
+            continue;
+        }
+        let result = match ctx.mutated {
+            Some(index) if ctx.ret == "void" => {
+                block.push_str(&format!("        {};\n", call));
+                ctx.params[index].name.clone()
+            }
+            _ => {
+                block.push_str(&format!("        auto result = {};\n", call));
+                String::from("result")
+            }
+        };
+        let expected = ctx.expected(case);
+        if ctx.is_double() {
+            block.push_str(&format!(
+                "        bool passed = fabs({} - {}) < 1e-5;\n",
+                result, expected
+            ));
+        } else {
+            block.push_str(&format!(
+                "        string expected = {};\n        bool passed = serialize({}) == expected;\n",
+                serde_json::to_string(&expected).unwrap_or_default(),
+                result
+            ));
+        }
+        block.push_str(&format!(
+            "        if (passed) cout << \"Example {0}: OK\" << endl;\n        else {{ failed++; cout << \"Example {0}: FAILED, got \" << serialize({1}) << endl; }}\n    }}\n",
+            number + 1,
+            result
+        ));
+        source.push_str(&block);
+    }
+
+    source.push_str(
+        "    cout << total - failed << \"/\" << total << \" examples passed\" << endl;\n    return failed ? 1 : 0;\n}\n",
+    );
+    source
+}
+
+fn java_type(ty: &str) -> Option<String> {
+    let ty = match ty {
+        "integer" => "int",
+        "long" => "long",
+        "double" => "double",
+        "boolean" => "boolean",
+        "string" => "String",
+        "character" => "char",
+        "ListNode" => "ListNode",
+        "TreeNode" => "TreeNode",
+        _ => {
+            if let Some(inner) = ty.strip_suffix("[]") {
+                return java_type(inner).map(|inner| format!("{}[]", inner));
+            }
+            let inner = element_type(ty)?;
+            return java_boxed_type(inner).map(|inner| format!("List<{}>", inner));
+        }
+    };
+    Some(String::from(ty))
+}
+
+fn java_boxed_type(ty: &str) -> Option<String> {
+    let boxed = match ty {
+        "integer" => "Integer",
+        "long" => "Long",
+        "double" => "Double",
+        "boolean" => "Boolean",
+        "character" => "Character",
+        _ => return java_type(ty),
+    };
+    Some(String::from(boxed))
+}
+
+fn java_literal(value: &Value, ty: &str) -> Option<String> {
+    match ty {
+        "integer" => value.as_i64().map(|num| num.to_string()),
+        "long" => value.as_i64().map(|num| format!("{}L", num)),
+        "double" => value.as_f64().map(|num| format!("{:?}", num)),
+        "boolean" => value.as_bool().map(|flag| flag.to_string()),
+        "string" => json_string(value),
+        "character" => char_literal(value),
+        "ListNode" => Some(format!(
+            "makeList(new int[]{{{}}})",
+            int_list(value)?.join(", ")
+        )),
+        "TreeNode" => Some(format!(
+            "makeTree(new Integer[]{{{}}})",
+            tree_list(value, "null")?.join(", ")
+        )),
+        _ => {
+            let inner = element_type(ty)?;
+            let values = value
+                .as_array()?
+                .iter()
+                .map(|val| java_literal(val, inner))
+                .collect::<Option<Vec<String>>>()?;
+            if ty.ends_with("[]") {
+                Some(format!("new {}{{{}}}", java_type(ty)?, values.join(", ")))
+            } else {
+                Some(format!(
+                    "new ArrayList<>(Arrays.asList({}))",
+                    values.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+const JAVA_HELPERS: &str = r#"class ListNode {
+    int val;
+    ListNode next;
+    ListNode() {}
+    ListNode(int val) { this.val = val; }
+    ListNode(int val, ListNode next) { this.val = val; this.next = next; }
+}
+
+class TreeNode {
+    int val;
+    TreeNode left;
+    TreeNode right;
+    TreeNode() {}
+    TreeNode(int val) { this.val = val; }
+    TreeNode(int val, TreeNode left, TreeNode right) { this.val = val; this.left = left; this.right = right; }
+}
+"#;
+
+const JAVA_MAIN_HELPERS: &str = r#"    static ListNode makeList(int[] values) {
+        ListNode head = null;
+        for (int i = values.length - 1; i >= 0; i--) head = new ListNode(values[i], head);
+        return head;
+    }
+
+    static TreeNode makeTree(Integer[] values) {
+        if (values.length == 0 || values[0] == null) return null;
+        TreeNode root = new TreeNode(values[0]);
+        Deque<TreeNode> nodes = new ArrayDeque<>();
+        nodes.add(root);
+        int i = 1;
+        while (!nodes.isEmpty() && i < values.length) {
+            TreeNode node = nodes.poll();
+            if (i < values.length && values[i] != null) { node.left = new TreeNode(values[i]); nodes.add(node.left); }
+            i++;
+            if (i < values.length && values[i] != null) { node.right = new TreeNode(values[i]); nodes.add(node.right); }
+            i++;
+        }
+        return root;
+    }
+
+    static String quote(String value) {
+        return "\"" + value.replace("\\", "\\\\").replace("\"", "\\\"") + "\"";
+    }
+
+    static String serialize(Object value) {
+        if (value == null) return "null";
+        if (value instanceof String) return quote((String) value);
+        if (value instanceof Character) return quote(String.valueOf(value));
+        if (value instanceof ListNode) {
+            List<String> values = new ArrayList<>();
+            for (ListNode node = (ListNode) value; node != null; node = node.next) values.add(String.valueOf(node.val));
+            return "[" + String.join(",", values) + "]";
+        }
+        if (value instanceof TreeNode) {
+            List<String> values = new ArrayList<>();
+            Deque<TreeNode> nodes = new LinkedList<>();
+            nodes.add((TreeNode) value);
+            while (!nodes.isEmpty()) {
+                TreeNode node = nodes.poll();
+                values.add(node == null ? "null" : String.valueOf(node.val));
+                if (node != null) { nodes.add(node.left); nodes.add(node.right); }
+            }
+            while (!values.isEmpty() && values.get(values.size() - 1).equals("null")) values.remove(values.size() - 1);
+            return "[" + String.join(",", values) + "]";
+        }
+        if (value.getClass().isArray()) {
+            List<String> values = new ArrayList<>();
+            for (int i = 0; i < java.lang.reflect.Array.getLength(value); i++) values.add(serialize(java.lang.reflect.Array.get(value, i)));
+            return "[" + String.join(",", values) + "]";
+        }
+        if (value instanceof Collection) {
+            List<String> values = new ArrayList<>();
+            for (Object item : (Collection<?>) value) values.add(serialize(item));
+            return "[" + String.join(",", values) + "]";
+        }
+        return String.valueOf(value);
+    }
+"#;
+
+fn java_harness(ctx: &Context) -> String {
+    let mut source = ctx.header.join("\n");
+    source.push_str("\n\nimport java.util.*;\n\n");
+    source.push_str(JAVA_HELPERS);
+    source.push('\n');
+    source.push_str(
+        fill_braced_bodies(
+            &ctx.snippet,
+            "throw new UnsupportedOperationException(\"not implemented\");",
+        )
+        .trim_end(),
+    );
+    source.push_str("\n\npublic class Main {\n");
+    source.push_str(JAVA_MAIN_HELPERS);
+    source.push_str("\n    public static void main(String[] args) {\n        int failed = 0;\n        int total = 0;\n");
+
+    for (number, case) in ctx.cases.iter().enumerate() {
+        let mut block = String::from("        {\n            total++;\n");
+        let mut names = Vec::new();
+        let mut ok = true;
+        for (param, arg) in ctx.params.iter().zip(&case.args) {
+            match (java_type(&param.type_), java_literal(arg, &param.type_)) {
+                (Some(ty), Some(literal)) => {
+                    block.push_str(&format!(
+                        "            {} {} = {};\n",
+                        ty, param.name, literal
+                    ));
+                    names.push(param.name.clone());
+                }
+                _ => ok = false,
+            }
+        }
+        if !ok {
+            continue;
+        }
+
+        let call = format!("new Solution().{}({})", ctx.method, names.join(", "));
+        if !ctx.checks_result() {
+            block.push_str(&format!(
+                "            {};\n            System.out.println(\"Example {}: ran, no result to compare\");\n        }}\n",
+                call,
+                number + 1
+            ));
+            source.push_str(&block);
+            continue;
+        }
+        let result = match ctx.mutated {
+            Some(index) if ctx.ret == "void" => {
+                block.push_str(&format!("            {};\n", call));
+                ctx.params[index].name.clone()
+            }
+            _ => {
+                block.push_str(&format!("            Object result = {};\n", call));
+                String::from("result")
+            }
+        };
+        let expected = ctx.expected(case);
+        if ctx.is_double() {
+            block.push_str(&format!(
+                "            boolean passed = Math.abs((Double) {} - {}) < 1e-5;\n",
+                result, expected
+            ));
+        } else {
+            block.push_str(&format!(
+                "            String expected = {};\n            boolean passed = serialize({}).equals(expected);\n",
+                serde_json::to_string(&expected).unwrap_or_default(),
+                result
+            ));
+        }
+        block.push_str(&format!(
+            "            if (passed) System.out.println(\"Example {0}: OK\");\n            else {{ failed++; System.out.println(\"Example {0}: FAILED, got \" + serialize({1})); }}\n        }}\n",
+            number + 1,
+            result
+        ));
+        source.push_str(&block);
+    }
+
+    source.push_str(
+        "        System.out.println((total - failed) + \"/\" + total + \" examples passed\");\n        System.exit(failed > 0 ? 1 : 0);\n    }\n}\n",
+    );
+    source
+}
+
+fn go_type(ty: &str) -> Option<String> {
+    let ty = match ty {
+        "integer" => "int",
+        "long" => "int64",
+        "double" => "float64",
+        "boolean" => "bool",
+        "string" => "string",
+        "character" => "byte",
+        "ListNode" => "*ListNode",
+        "TreeNode" => "*TreeNode",
+        _ => {
+            return element_type(ty)
+                .and_then(go_type)
+                .map(|inner| format!("[]{}", inner))
+        }
+    };
+    Some(String::from(ty))
+}
+
+fn go_literal(value: &Value, ty: &str) -> Option<String> {
+    match ty {
+        "integer" | "long" => value.as_i64().map(|num| num.to_string()),
+        "double" => value.as_f64().map(|num| format!("{:?}", num)),
+        "boolean" => value.as_bool().map(|flag| flag.to_string()),
+        "string" => json_string(value),
+        "character" => char_literal(value),
+        "ListNode" => Some(format!(
+            "makeList([]int{{{}}})",
+            int_list(value)?.join(", ")
+        )),
+        "TreeNode" => Some(format!(
+            "makeTree([]interface{{}}{{{}}})",
+            tree_list(value, "nil")?.join(", ")
+        )),
+        _ => {
+            let inner = element_type(ty)?;
+            let values = value
+                .as_array()?
+                .iter()
+                .map(|val| go_literal(val, inner))
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("{}{{{}}}", go_type(ty)?, values.join(", ")))
+        }
+    }
+}
+
+const GO_HELPERS: &str = r#"type ListNode struct {
+	Val  int
+	Next *ListNode
+}
+
+type TreeNode struct {
+	Val   int
+	Left  *TreeNode
+	Right *TreeNode
+}
+
+func makeList(values []int) *ListNode {
+	var head *ListNode
+	for i := len(values) - 1; i >= 0; i-- {
+		head = &ListNode{Val: values[i], Next: head}
+	}
+	return head
+}
+
+func makeTree(values []interface{}) *TreeNode {
+	if len(values) == 0 || values[0] == nil {
+		return nil
+	}
+	root := &TreeNode{Val: values[0].(int)}
+	nodes := []*TreeNode{root}
+	for i := 1; len(nodes) > 0 && i < len(values); i += 2 {
+		node := nodes[0]
+		nodes = nodes[1:]
+		if values[i] != nil {
+			node.Left = &TreeNode{Val: values[i].(int)}
+			nodes = append(nodes, node.Left)
+		}
+		if i+1 < len(values) && values[i+1] != nil {
+			node.Right = &TreeNode{Val: values[i+1].(int)}
+			nodes = append(nodes, node.Right)
+		}
+	}
+	return root
+}
+
+func plain(value interface{}) interface{} {
+	switch v := value.(type) {
+	case *ListNode:
+		values := []int{}
+		for node := v; node != nil; node = node.Next {
+			values = append(values, node.Val)
+		}
+		return values
+	case *TreeNode:
+		values := []interface{}{}
+		nodes := []*TreeNode{v}
+		for len(nodes) > 0 {
+			node := nodes[0]
+			nodes = nodes[1:]
+			if node == nil {
+				values = append(values, nil)
+				continue
+			}
+			values = append(values, node.Val)
+			nodes = append(nodes, node.Left, node.Right)
+		}
+		for len(values) > 0 && values[len(values)-1] == nil {
+			values = values[:len(values)-1]
+		}
+		return values
+	case byte:
+		return string(v)
+	case []byte:
+		values := []string{}
+		for _, c := range v {
+			values = append(values, string(c))
+		}
+		return values
+	case [][]byte:
+		values := [][]string{}
+		for _, row := range v {
+			values = append(values, plain(row).([]string))
+		}
+		return values
+	}
+	return value
+}
+
+func serialize(value interface{}) string {
+	var out bytes.Buffer
+	encoder := json.NewEncoder(&out)
+	encoder.SetEscapeHTML(false)
+	encoder.Encode(plain(value))
+	return strings.TrimSpace(out.String())
+}
+
+func approx(a, b float64) bool {
+	return math.Abs(a-b) < 1e-5
+}
+"#;
+
+fn go_harness(ctx: &Context) -> String {
+    let mut source = ctx.header.join("\n");
+    source.push_str(
+        "\n\npackage main\n\nimport (\n\t\"bytes\"\n\t\"encoding/json\"\n\t\"fmt\"\n\t\"math\"\n\t\"os\"\n\t\"strings\"\n)\n\n",
+    );
+    source.push_str(GO_HELPERS);
+    source.push('\n');
+    source.push_str(fill_braced_bodies(&ctx.snippet, "panic(\"not implemented\")").trim_end());
+    source.push_str("\n\nfunc main() {\n\tfailed, total := 0, 0\n");
+
+    for (number, case) in ctx.cases.iter().enumerate() {
+        let mut block = String::from("\t{\n\t\ttotal++\n");
+        let mut names = Vec::new();
+        let mut ok = true;
+        for (param, arg) in ctx.params.iter().zip(&case.args) {
+            match (go_type(&param.type_), go_literal(arg, &param.type_)) {
+                (Some(ty), Some(literal)) => {
+                    block.push_str(&format!("\t\tvar {} {} = {}\n", param.name, ty, literal));
+                    names.push(param.name.clone());
+                }
+                _ => ok = false,
+            }
+        }
+        if !ok {
+            continue;
+        }
+
+        let call = format!("{}({})", ctx.method, names.join(", "));
+        if !ctx.checks_result() {
+            block.push_str(&format!(
+                "\t\t{}\n\t\tfmt.Println(\"Example {}: ran, no result to compare\")\n\t}}\n",
+                call,
+                number + 1
+            ));
+            source.push_str(&block);
+            continue;
+        }
+        let result = match ctx.mutated {
+            Some(index) if ctx.ret == "void" => {
+                block.push_str(&format!("\t\t{}\n", call));
+                ctx.params[index].name.clone()
+            }
+            _ => {
+                block.push_str(&format!("\t\tresult := {}\n", call));
+                String::from("result")
+            }
+        };
+        let expected = ctx.expected(case);
+        if ctx.is_double() {
+            block.push_str(&format!("\t\tpassed := approx({}, {})\n", result, expected));
+        } else {
+            block.push_str(&format!(
+                "\t\texpected := {}\n\t\tpassed := serialize({}) == expected\n",
+                serde_json::to_string(&expected).unwrap_or_default(),
+                result
+            ));
+        }
+        block.push_str(&format!(
+            "\t\tif passed {{\n\t\t\tfmt.Println(\"Example {0}: OK\")\n\t\t}} else {{\n\t\t\tfailed++\n\t\t\tfmt.Println(\"Example {0}: FAILED, got\", serialize({1}))\n\t\t}}\n\t}}\n",
+            number + 1,
+            result
+        ));
+        source.push_str(&block);
+    }
+
+    source.push_str(
+        "\tfmt.Printf(\"%d/%d examples passed\\n\", total-failed, total)\n\tif failed > 0 {\n\t\tos.Exit(1)\n\t}\n}\n",
+    );
+    source
+}
+
+fn typescript_literal(value: &Value, ty: &str) -> Option<String> {
+    match ty {
+        "ListNode" => Some(format!("makeList([{}])", int_list(value)?.join(", "))),
+        "TreeNode" => Some(format!(
+            "makeTree([{}])",
+            tree_list(value, "null")?.join(", ")
+        )),
+        _ => match value {
+            Value::Array(values) => {
+                let inner = element_type(ty).unwrap_or_default();
+                let values = values
+                    .iter()
+                    .map(|val| typescript_literal(val, inner))
+                    .collect::<Option<Vec<String>>>()?;
+                Some(format!("[{}]", values.join(", ")))
+            }
+            Value::Object(_) => None,
+            value => serde_json::to_string(value).ok(),
+        },
+    }
+}
+
+const TYPESCRIPT_HELPERS: &str = r#"class ListNode {
+    val: number
+    next: ListNode | null
+    constructor(val?: number, next?: ListNode | null) {
+        this.val = val === undefined ? 0 : val
+        this.next = next === undefined ? null : next
+    }
+}
+
+class TreeNode {
+    val: number
+    left: TreeNode | null
+    right: TreeNode | null
+    constructor(val?: number, left?: TreeNode | null, right?: TreeNode | null) {
+        this.val = val === undefined ? 0 : val
+        this.left = left === undefined ? null : left
+        this.right = right === undefined ? null : right
+    }
+}
+
+function makeList(values: number[]): ListNode | null {
+    let head: ListNode | null = null
+    for (let i = values.length - 1; i >= 0; i--) head = new ListNode(values[i], head)
+    return head
+}
+
+function makeTree(values: (number | null)[]): TreeNode | null {
+    if (values.length === 0 || values[0] === null) return null
+    const root = new TreeNode(values[0])
+    const nodes: TreeNode[] = [root]
+    let i = 1
+    while (nodes.length > 0 && i < values.length) {
+        const node = nodes.shift()!
+        const left = values[i++]
+        if (left !== null && left !== undefined) { node.left = new TreeNode(left); nodes.push(node.left) }
+        const right = values[i++]
+        if (right !== null && right !== undefined) { node.right = new TreeNode(right); nodes.push(node.right) }
+    }
+    return root
+}
+
+function plain(value: any): any {
+    if (value instanceof ListNode) {
+        const values: number[] = []
+        for (let node: ListNode | null = value; node; node = node.next) values.push(node.val)
+        return values
+    }
+    if (value instanceof TreeNode) {
+        const values: (number | null)[] = []
+        const nodes: (TreeNode | null)[] = [value]
+        while (nodes.length > 0) {
+            const node = nodes.shift()!
+            values.push(node ? node.val : null)
+            if (node) nodes.push(node.left, node.right)
+        }
+        while (values.length > 0 && values[values.length - 1] === null) values.pop()
+        return values
+    }
+    if (Array.isArray(value)) return value.map(plain)
+    return value
+}
+
+function serialize(value: any): string {
+    return JSON.stringify(plain(value) ?? null)
+}
+"#;
+
+fn typescript_harness(ctx: &Context) -> String {
+    let mut source = ctx.header.join("\n");
+    source.push_str("\n\n");
+    source.push_str(TYPESCRIPT_HELPERS);
+    source.push('\n');
+    source.push_str(
+        fill_braced_bodies(&ctx.snippet, "throw new Error(\"not implemented\")").trim_end(),
+    );
+    source.push_str("\n\nconst cases: [any[], string][] = [\n");
+    for case in &ctx.cases {
+        let args = ctx
+            .params
+            .iter()
+            .zip(&case.args)
+            .map(|(param, arg)| typescript_literal(arg, &param.type_))
+            .collect::<Option<Vec<String>>>();
+        if let Some(args) = args {
+            source.push_str(&format!(
+                "    [[{}], {}],\n",
+                args.join(", "),
+                serde_json::to_string(&ctx.expected(case)).unwrap_or_default()
+            ));
+        }
+    }
+    source.push_str("]\n\nlet failed = 0\ncases.forEach(([args, expected], index) => {\n");
+    if !ctx.checks_result() {
+        source.push_str(&format!(
+            "    ;({} as any)(...args)\n    console.log(`Example ${{index + 1}}: ran, no result to compare`)\n}})\n",
+            ctx.method
+        ));
+        return source;
+    }
+    source.push_str(&format!(
+        "    let result: any = ({} as any)(...args)\n",
+        ctx.method
+    ));
+    if let (Some(index), "void") = (ctx.mutated, ctx.ret.as_str()) {
+        source.push_str(&format!("    result = args[{}]\n", index));
+    }
+    if ctx.is_double() {
+        source.push_str("    const passed = Math.abs(result - JSON.parse(expected)) < 1e-5\n");
+    } else {
+        source.push_str("    const passed = serialize(result) === expected\n");
+    }
+    source.push_str(
+        r#"    if (passed) {
+        console.log(`Example ${index + 1}: OK`)
+    } else {
+        failed++
+        console.log(`Example ${index + 1}: FAILED, expected ${expected}, got ${serialize(result)}`)
+    }
+})
+console.log(`${cases.length - failed}/${cases.length} examples passed`)
+if (failed > 0) process.exit(1)
+"#,
+    );
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fill_braced_bodies, fill_python_bodies};
+    use crate::{fixtures, ProgrammingLanguage};
+
+    #[test]
+    fn fills_empty_braced_bodies() {
+        let filled = fill_braced_bodies(
+            "/**\n * struct ListNode {\n * }\n */\nclass Solution {\npublic:\n    int f(int x) {\n        \n    }\n    int g() {\n        return 1;\n    }\n};",
+            "throw 1;",
+        );
+        assert_eq!(
+            filled,
+            "/**\n * struct ListNode {\n * }\n */\nclass Solution {\npublic:\n    int f(int x) {\n        throw 1;\n    }\n    int g() {\n        return 1;\n    }\n};"
+        );
+    }
+
+    #[test]
+    fn fills_empty_python_bodies() {
+        assert_eq!(
+            fill_python_bodies("class Solution:\n    def f(self, x: int) -> int:\n        "),
+            "class Solution:\n    def f(self, x: int) -> int:\n        raise NotImplementedError\n        "
+        );
+    }
+
+    #[test]
+    fn two_sum_harnesses() {
+        let problem = fixtures::two_sum();
+
+        let cpp = problem.test_harness(ProgrammingLanguage::CPP).unwrap();
+        assert_eq!(cpp.file_name, "solution.cpp");
+        assert!(cpp.source.starts_with("// 1. Two Sum\n"));
+        assert!(cpp.source.contains(
+            "    vector<int> twoSum(vector<int>& nums, int target) {\n        throw runtime_error(\"not implemented\");\n    }"
+        ));
+        assert!(cpp.source.contains(
+            "        vector<int> nums = {2, 7, 11, 15};\n        int target = 9;\n        auto result = Solution().twoSum(nums, target);\n        string expected = \"[0,1]\";\n        bool passed = serialize(result) == expected;\n"
+        ));
+
+        let java = problem.test_harness(ProgrammingLanguage::Java).unwrap();
+        assert_eq!(java.file_name, "Main.java");
+        assert!(java.source.contains(
+            "            int[] nums = new int[]{3, 2, 4};\n            int target = 6;\n            Object result = new Solution().twoSum(nums, target);\n            String expected = \"[1,2]\";\n"
+        ));
+
+        let go = problem.test_harness(ProgrammingLanguage::Go).unwrap();
+        assert!(go.source.contains(
+            "\t\tvar nums []int = []int{2, 7, 11, 15}\n\t\tvar target int = 9\n\t\tresult := twoSum(nums, target)\n\t\texpected := \"[0,1]\"\n"
+        ));
+
+        let python = problem.test_harness(ProgrammingLanguage::Python3).unwrap();
+        assert!(python.source.contains(
+            "CASES = [\n    ([[2, 7, 11, 15], 9], \"[0,1]\"),\n    ([[3, 2, 4], 6], \"[1,2]\"),\n]\n"
+        ));
+
+        let typescript = problem
+            .test_harness(ProgrammingLanguage::TypeScript)
+            .unwrap();
+        assert_eq!(typescript.file_name, "solution.ts");
+        assert!(typescript.source.contains(
+            "function twoSum(nums: number[], target: number): number[] {\n    throw new Error(\"not implemented\")\n};"
+        ));
+        assert!(typescript.source.contains(
+            "const cases: [any[], string][] = [\n    [[[2, 7, 11, 15], 9], \"[0,1]\"],\n    [[[3, 2, 4], 6], \"[1,2]\"],\n]\n"
+        ));
+        assert!(typescript.source.contains(
+            "    let result: any = (twoSum as any)(...args)\n    const passed = serialize(result) === expected\n"
+        ));
+
+        let rust = problem.test_harness(ProgrammingLanguage::Rust).unwrap();
+        assert_eq!(rust.file_name, "lib.rs");
+        assert_eq!(rust.source, problem.rust_scaffold().unwrap().source);
+    }
+
+    #[test]
+    fn list_and_tree_harnesses() {
+        let cpp = fixtures::merge_two_lists()
+            .test_harness(ProgrammingLanguage::CPP)
+            .unwrap();
+        assert!(cpp.source.contains(
+            "        ListNode* list1 = make_list({1, 2, 4});\n        ListNode* list2 = make_list({1, 3, 4});\n        auto result = Solution().mergeTwoLists(list1, list2);\n        string expected = \"[1,1,2,3,4,4]\";\n"
+        ));
+
+        let java = fixtures::merge_two_lists()
+            .test_harness(ProgrammingLanguage::Java)
+            .unwrap();
+        assert!(java
+            .source
+            .contains("            ListNode list2 = makeList(new int[]{1, 3, 4});\n"));
+
+        let typescript = fixtures::merge_two_lists()
+            .test_harness(ProgrammingLanguage::TypeScript)
+            .unwrap();
+        assert!(typescript
+            .source
+            .contains("    [[makeList([1, 2, 4]), makeList([1, 3, 4])], \"[1,1,2,3,4,4]\"],\n"));
+
+        let cpp = fixtures::invert_tree()
+            .test_harness(ProgrammingLanguage::CPP)
+            .unwrap();
+        assert!(cpp
+            .source
+            .contains("auto result = Solution().invertTree(root);\n"));
+        assert!(cpp
+            .source
+            .contains("string expected = \"[4,7,2,9,6,3,1]\";\n"));
+    }
+
+    #[test]
+    fn design_problems_have_no_harness() {
+        assert!(fixtures::min_stack()
+            .test_harness(ProgrammingLanguage::CPP)
+            .is_err());
+        assert!(fixtures::two_sum()
+            .test_harness(ProgrammingLanguage::Ruby)
+            .is_err());
+    }
+
+    #[test]
+    fn void_without_output_param_is_called_without_result() {
+        let problem = fixtures::rotate_without_output();
+
+        let cpp = problem.test_harness(ProgrammingLanguage::CPP).unwrap();
+        assert!(cpp.source.contains(
+            "        Solution().rotate(nums, k);\n        cout << \"Example 1: ran, no result to compare\" << endl;\n    }\n"
+        ));
+        assert!(!cpp.source.contains("auto result"));
+
+        let java = problem.test_harness(ProgrammingLanguage::Java).unwrap();
+        assert!(java.source.contains(
+            "            new Solution().rotate(nums, k);\n            System.out.println(\"Example 1: ran, no result to compare\");\n        }\n"
+        ));
+        assert!(!java.source.contains("Object result"));
+
+        let go = problem.test_harness(ProgrammingLanguage::Go).unwrap();
+        assert!(go.source.contains(
+            "\t\trotate(nums, k)\n\t\tfmt.Println(\"Example 1: ran, no result to compare\")\n\t}\n"
+        ));
+        assert!(!go.source.contains("result :="));
+
+        let typescript = problem
+            .test_harness(ProgrammingLanguage::TypeScript)
+            .unwrap();
+        assert!(typescript.source.contains(
+            "    ;(rotate as any)(...args)\n    console.log(`Example ${index + 1}: ran, no result to compare`)\n})\n"
+        ));
+        assert!(!typescript.source.contains("let result"));
+    }
+}
@@ -0,0 +1,149 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use serde_json::Value;
+
+use crate::error::Errors;
+
+mod list_node;
+mod tree_node;
+
+pub use list_node::{list_from_vec, ListNode};
+pub use tree_node::{tree_from_vec, TreeNode};
+
+pub fn list_to_vec(head: &Option<Box<ListNode>>) -> Vec<i32> {
+    let mut values = Vec::new();
+    let mut node = head.as_deref();
+    while let Some(current) = node {
+        values.push(current.val);
+        node = current.next.as_deref();
+    }
+    values
+}
+
+pub fn list_from_str(literal: &str) -> Result<Option<Box<ListNode>>, Errors> {
+    let values = parse_literal(literal)?
+        .into_iter()
+        .map(|val| {
+            val.ok_or_else(|| {
+                Errors::ApiError(format!("Linked list `{}` can not contain null", literal))
+            })
+        })
+        .collect::<Result<Vec<i32>, Errors>>()?;
+    Ok(list_from_vec(&values))
+}
+
+pub fn list_to_string(head: &Option<Box<ListNode>>) -> String {
+    let values = list_to_vec(head)
+        .iter()
+        .map(|val| val.to_string())
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(","))
+}
+
+pub fn tree_to_vec(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
+    let mut values = Vec::new();
+    let mut queue = VecDeque::from([root.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        match node {
+            Some(node) => {
+                let node = node.borrow();
+                values.push(Some(node.val));
+                queue.push_back(node.left.clone());
+                queue.push_back(node.right.clone());
+            }
+            None => values.push(None),
+        }
+    }
+
+    while values.last() == Some(&None) {
+        values.pop();
+    }
+    values
+}
+
+pub fn tree_from_str(literal: &str) -> Result<Option<Rc<RefCell<TreeNode>>>, Errors> {
+    Ok(tree_from_vec(&parse_literal(literal)?))
+}
+
+pub fn tree_to_string(root: &Option<Rc<RefCell<TreeNode>>>) -> String {
+    let values = tree_to_vec(root)
+        .iter()
+        .map(|val| val.map_or(String::from("null"), |val| val.to_string()))
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(","))
+}
+
+fn parse_literal(literal: &str) -> Result<Vec<Option<i32>>, Errors> {
+    let values = serde_json::from_str::<Vec<Value>>(literal.trim())?;
+    values
+        .iter()
+        .map(|val| match val {
+            Value::Null => Ok(None),
+            val => val
+                .as_i64()
+                .and_then(|val| i32::try_from(val).ok())
+                .map(Some)
+                .ok_or_else(|| {
+                    Errors::ApiError(format!("Value `{}` in `{}` is not an i32", val, literal))
+                }),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        list_from_str, list_from_vec, list_to_string, list_to_vec, tree_from_str, tree_from_vec,
+        tree_to_string, tree_to_vec,
+    };
+
+    #[test]
+    fn list_round_trip() {
+        let head = list_from_str("[1, 2, 3]").unwrap();
+        assert_eq!(list_to_vec(&head), vec![1, 2, 3]);
+        assert_eq!(list_to_string(&head), "[1,2,3]");
+        assert_eq!(head, list_from_vec(&[1, 2, 3]));
+
+        let empty = list_from_str("[]").unwrap();
+        assert_eq!(empty, None);
+        assert_eq!(list_to_string(&empty), "[]");
+    }
+
+    #[test]
+    fn tree_round_trip() {
+        let root = tree_from_str("[1,null,2,3]").unwrap();
+        assert_eq!(tree_to_vec(&root), vec![Some(1), None, Some(2), Some(3)]);
+        assert_eq!(tree_to_string(&root), "[1,null,2,3]");
+        {
+            let node = root.as_ref().unwrap().borrow();
+            assert!(node.left.is_none());
+            let right = node.right.as_ref().unwrap().borrow();
+            assert_eq!(right.val, 2);
+            assert_eq!(right.left.as_ref().unwrap().borrow().val, 3);
+        }
+
+        let empty = tree_from_str("[]").unwrap();
+        assert_eq!(empty, None);
+        assert_eq!(tree_to_string(&empty), "[]");
+        assert_eq!(tree_from_vec(&[None]), None);
+    }
+
+    #[test]
+    fn tree_drops_trailing_nulls() {
+        let root = tree_from_str("[3,9,20,null,null,15,7,null,null,null,null]").unwrap();
+        assert_eq!(tree_to_string(&root), "[3,9,20,null,null,15,7]");
+        assert_eq!(root, tree_from_str("[3,9,20,null,null,15,7]").unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(list_from_str("[1,null,2]").is_err());
+        assert!(list_from_str("[2147483648]").is_err());
+        assert!(list_from_str("[1.5]").is_err());
+        assert!(list_from_str("1,2").is_err());
+        assert!(tree_from_str("[1,-2147483649]").is_err());
+        assert!(tree_from_str("[1,\"2\"]").is_err());
+        assert!(list_from_str("[2147483647,-2147483648]").is_ok());
+    }
+}
@@ -0,0 +1,20 @@
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+}
+
+pub fn list_from_vec(values: &[i32]) -> Option<Box<ListNode>> {
+    let mut head = None;
+    for &val in values.iter().rev() {
+        head = Some(Box::new(ListNode { val, next: head }));
+    }
+    head
+}
@@ -0,0 +1,46 @@
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>,
+    pub right: Option<std::rc::Rc<std::cell::RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+pub fn tree_from_vec(values: &[Option<i32>]) -> Option<std::rc::Rc<std::cell::RefCell<TreeNode>>> {
+    use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+    let root = Rc::new(RefCell::new(TreeNode::new(
+        values.first().copied().flatten()?,
+    )));
+    let mut queue = VecDeque::from([Rc::clone(&root)]);
+    let mut values = values.iter().skip(1);
+
+    while let Some(node) = queue.pop_front() {
+        for is_left in [true, false] {
+            match values.next() {
+                Some(Some(val)) => {
+                    let child = Rc::new(RefCell::new(TreeNode::new(*val)));
+                    queue.push_back(Rc::clone(&child));
+                    if is_left {
+                        node.borrow_mut().left = Some(child);
+                    } else {
+                        node.borrow_mut().right = Some(child);
+                    }
+                }
+                Some(None) => {}
+                None => return Some(root),
+            }
+        }
+    }
+    Some(root)
+}
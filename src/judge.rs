@@ -0,0 +1,294 @@
+use std::time::Duration;
+
+use futures::{stream, Stream};
+use serde::de::DeserializeOwned;
+use tokio::time::Instant;
+
+use crate::{
+    error::Errors,
+    resources::{subm_send::SubmExecutionResult, test_send::TestExecutionResult},
+};
+
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone)]
+pub struct PollConfig {
+    pub(crate) interval: Duration,
+    pub(crate) backoff: f32,
+    pub(crate) max_interval: Duration,
+    pub(crate) max_wait: Option<Duration>,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            backoff: 1.5,
+            max_interval: Duration::from_secs(5),
+            max_wait: Some(Duration::from_secs(120)),
+        }
+    }
+}
+
+impl PollConfig {
+    pub fn set_interval(mut self, interval: Duration) -> PollConfig {
+        self.interval = interval.max(MIN_INTERVAL);
+        self
+    }
+
+    pub fn set_backoff(mut self, backoff: f32) -> PollConfig {
+        self.backoff = backoff.max(1.0);
+        self
+    }
+
+    pub fn set_max_interval(mut self, max_interval: Duration) -> PollConfig {
+        self.max_interval = max_interval.max(MIN_INTERVAL);
+        self
+    }
+
+    pub fn set_max_wait(mut self, max_wait: Option<Duration>) -> PollConfig {
+        self.max_wait = max_wait;
+        self
+    }
+
+    pub(crate) fn next_interval(&self, current: Duration) -> Duration {
+        let max_interval = self.max_interval.max(MIN_INTERVAL);
+        let next = current.clamp(MIN_INTERVAL, max_interval).as_secs_f32() * self.backoff.max(1.0);
+        Duration::try_from_secs_f32(next)
+            .unwrap_or(max_interval)
+            .clamp(MIN_INTERVAL, max_interval)
+    }
+}
+
+#[derive(Debug)]
+pub enum JudgeEvent {
+    Queued,
+    Running {
+        total_correct: Option<u32>,
+        total_testcases: Option<u32>,
+    },
+    Finished(Box<SubmExecutionResult>),
+    Failed(Errors),
+}
+
+pub(crate) trait CheckState {
+    fn state(&self) -> &str;
+}
+
+impl CheckState for TestExecutionResult {
+    fn state(&self) -> &str {
+        &self.state
+    }
+}
+
+impl CheckState for SubmExecutionResult {
+    fn state(&self) -> &str {
+        &self.state
+    }
+}
+
+fn judge_failure() -> Errors {
+    Errors::SendError(
+        "Sent code failure. Err may be occured by Unsupported lang for this problem".into(),
+    )
+}
+
+pub(crate) async fn check_once<T>(client: &reqwest::Client, id: &str) -> Result<T, Errors>
+where
+    T: DeserializeOwned,
+{
+    Ok(client
+        .get(format!(
+            "https://leetcode.com/submissions/detail/{}/check/",
+            id
+        ))
+        .send()
+        .await?
+        .json::<T>()
+        .await?)
+}
+
+pub(crate) async fn poll_check<T>(
+    client: &reqwest::Client,
+    id: &str,
+    config: &PollConfig,
+) -> Result<T, Errors>
+where
+    T: DeserializeOwned + CheckState,
+{
+    let polling = async {
+        let mut interval = config.interval;
+        loop {
+            let status = check_once::<T>(client, id).await?;
+            if status.state() == "SUCCESS" {
+                return Ok(status);
+            } else if status.state() == "FAILURE" {
+                return Err(judge_failure());
+            }
+            tokio::time::sleep(interval).await;
+            interval = config.next_interval(interval);
+        }
+    };
+
+    match config.max_wait {
+        Some(max_wait) => tokio::time::timeout(max_wait, polling)
+            .await
+            .map_err(|_| Errors::JudgeTimeout(max_wait))?,
+        None => polling.await,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Progress {
+    Queued,
+    Running(Option<u32>, Option<u32>),
+}
+
+struct EventState {
+    client: reqwest::Client,
+    id: String,
+    config: PollConfig,
+    interval: Duration,
+    deadline: Option<Instant>,
+    last: Option<Progress>,
+    done: bool,
+}
+
+pub(crate) fn event_stream(
+    client: reqwest::Client,
+    id: String,
+    config: PollConfig,
+) -> impl Stream<Item = JudgeEvent> {
+    let state = EventState {
+        client,
+        id,
+        interval: config.interval,
+        deadline: config.max_wait.map(|max_wait| Instant::now() + max_wait),
+        config,
+        last: None,
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        loop {
+            let waited = state.last.is_some();
+            let check = async {
+                if waited {
+                    tokio::time::sleep(state.interval).await;
+                }
+                check_once::<SubmExecutionResult>(&state.client, &state.id).await
+            };
+            let checked = match state.deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, check).await,
+                None => Ok(check.await),
+            };
+            if waited {
+                state.interval = state.config.next_interval(state.interval);
+            }
+
+            let status = match checked {
+                Ok(Ok(status)) => status,
+                Ok(Err(err)) => {
+                    state.done = true;
+                    return Some((JudgeEvent::Failed(err), state));
+                }
+                Err(_) => {
+                    let max_wait = state.config.max_wait.unwrap_or_default();
+                    state.done = true;
+                    return Some((JudgeEvent::Failed(Errors::JudgeTimeout(max_wait)), state));
+                }
+            };
+
+            let progress = match status.state.as_str() {
+                "SUCCESS" => {
+                    state.done = true;
+                    return Some((JudgeEvent::Finished(Box::new(status)), state));
+                }
+                "FAILURE" => {
+                    state.done = true;
+                    return Some((JudgeEvent::Failed(judge_failure()), state));
+                }
+                "STARTED" => Progress::Running(status.total_correct, status.total_testcases),
+                _ => Progress::Queued,
+            };
+
+            if state.last.as_ref() != Some(&progress) {
+                let event = match progress {
+                    Progress::Queued => JudgeEvent::Queued,
+                    Progress::Running(total_correct, total_testcases) => JudgeEvent::Running {
+                        total_correct,
+                        total_testcases,
+                    },
+                };
+                state.last = Some(progress);
+                return Some((event, state));
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PollConfig, MIN_INTERVAL};
+
+    #[test]
+    fn next_interval_grows_up_to_max() {
+        let config = PollConfig::default()
+            .set_interval(Duration::from_secs(1))
+            .set_backoff(2.0)
+            .set_max_interval(Duration::from_secs(5));
+
+        assert_eq!(
+            config.next_interval(Duration::from_secs(1)),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            config.next_interval(Duration::from_secs(4)),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            config.next_interval(Duration::from_secs(5)),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn next_interval_survives_bad_backoff() {
+        let config = PollConfig::default().set_max_interval(Duration::from_secs(5));
+        let current = Duration::from_secs(1);
+
+        let infinite = config.clone().set_backoff(f32::INFINITY);
+        assert_eq!(infinite.next_interval(current), Duration::from_secs(5));
+
+        let negative = PollConfig {
+            backoff: -1.0,
+            ..config.clone()
+        };
+        assert_eq!(negative.next_interval(current), current);
+
+        let nan = PollConfig {
+            backoff: f32::NAN,
+            ..config
+        };
+        assert_eq!(nan.next_interval(current), current);
+    }
+
+    #[test]
+    fn zero_interval_is_clamped() {
+        let config = PollConfig::default()
+            .set_interval(Duration::ZERO)
+            .set_max_interval(Duration::ZERO);
+
+        assert_eq!(config.interval, MIN_INTERVAL);
+        assert_eq!(config.max_interval, MIN_INTERVAL);
+        assert_eq!(config.next_interval(Duration::ZERO), MIN_INTERVAL);
+
+        let config = PollConfig::default().set_backoff(2.0);
+        assert!(config.next_interval(Duration::ZERO) >= MIN_INTERVAL * 2);
+    }
+}
@@ -1,19 +1,47 @@
+use std::path::PathBuf;
+
+use contest::Contest;
 use error::Errors;
+use export::SolutionExporter;
+use judge::PollConfig;
 use problem_actions::Problem;
 use problem_build::{Filters, ProblemBuilder};
 use profile::{MyProfile, UserProfile};
 use reqwest::header::{HeaderMap, HeaderValue};
 use resources::{
-    cookie::CookieData, descr::ProblemData, fav_list::FavoriteList,
+    contest::{
+        ContestDetail, ContestRankingPage, PastContests, PastContestsPage, UpcomingContest,
+        UpcomingContests,
+    },
+    cookie::CookieData,
+    descr::ProblemData,
+    fav_list::FavoriteNode,
     problemfulldata::ProblemFullData,
+    subm_details::{SubmissionDetails, SubmissionDetailsNode},
+    subm_send::{SubmExecutionResult, SubmissionId},
+    test_send::{InterpretId, TestExecutionResult},
 };
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::json;
 
+pub mod assets;
+pub mod contest;
 pub mod error;
+pub mod export;
+#[cfg(test)]
+mod fixtures;
+pub mod harness;
+pub mod helpers;
+pub mod judge;
+pub mod lists;
+pub mod notifications;
 pub mod problem_actions;
 pub mod problem_build;
 pub mod profile;
+pub mod render;
 pub mod resources;
+pub mod scaffold;
+pub mod submissions;
 
 #[derive(Debug)]
 pub struct UserApi {
@@ -34,7 +62,7 @@ impl UserApi {
         headers.insert("Sec-Fetch-Mode", HeaderValue::from_static("cors"));
         headers.insert("Sec-Fetch-Site", HeaderValue::from_static("same-origin"));
 
-        let valid_data = Self::valid_check(headers.clone(), &cookie).await?;
+        let valid_data = Self::valid_check(headers.clone(), cookie).await?;
 
         let cookie = if valid_data.0 {
             cookie
@@ -46,7 +74,7 @@ impl UserApi {
 
         let token = valid_data.1;
 
-        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
+        headers.insert("Cookie", HeaderValue::from_str(cookie).unwrap());
         headers.insert("x-csrftoken", HeaderValue::from_str(&token).unwrap());
 
         let client = reqwest::Client::builder()
@@ -57,17 +85,14 @@ impl UserApi {
     }
 
     async fn valid_check(mut headers: HeaderMap, cookie: &str) -> Result<(bool, String), Errors> {
-        let token = if let Some(token) = cookie
-            .strip_prefix("csrftoken=")
-            .and_then(|val| Some(&val[..64]))
-        {
+        let token = if let Some(token) = cookie.strip_prefix("csrftoken=").map(|val| &val[..64]) {
             token
         } else {
             return Err(Errors::ApiError("Cannot take token from cookie".into()));
         };
 
-        headers.insert("Cookie", HeaderValue::from_str(&cookie).unwrap());
-        headers.insert("x-csrftoken", HeaderValue::from_str(&token).unwrap());
+        headers.insert("Cookie", HeaderValue::from_str(cookie).unwrap());
+        headers.insert("x-csrftoken", HeaderValue::from_str(token).unwrap());
         headers.insert("content-type", HeaderValue::from_static("application/json"));
 
         let json_data = json!({
@@ -100,8 +125,8 @@ impl UserApi {
 
     pub async fn set_problem(&self, problem_name: &str) -> Result<Problem, Errors> {
         let info = Self::fetch_problem_full_data(
-            &self,
-            Self::get_question_name(&self, String::from(problem_name)).await?,
+            self,
+            Self::get_question_name(self, String::from(problem_name)).await?,
         )
         .await?;
 
@@ -109,13 +134,14 @@ impl UserApi {
             client: self.client.clone(),
             task_search_name: info.0,
             full_data: info.1,
+            poll_config: PollConfig::default(),
         })
     }
 
     pub async fn set_problem_by_id(&self, problem_id: u32) -> Result<Problem, Errors> {
         let info = Self::fetch_problem_full_data(
-            &self,
-            Self::get_question_name(&self, problem_id.to_string()).await?,
+            self,
+            Self::get_question_name(self, problem_id.to_string()).await?,
         )
         .await?;
 
@@ -123,6 +149,18 @@ impl UserApi {
             client: self.client.clone(),
             task_search_name: info.0,
             full_data: info.1,
+            poll_config: PollConfig::default(),
+        })
+    }
+
+    pub async fn set_problem_by_slug(&self, title_slug: &str) -> Result<Problem, Errors> {
+        let info = Self::fetch_problem_full_data(self, String::from(title_slug)).await?;
+
+        Ok(Problem {
+            client: self.client.clone(),
+            task_search_name: info.0,
+            full_data: info.1,
+            poll_config: PollConfig::default(),
         })
     }
 
@@ -184,11 +222,63 @@ impl UserApi {
         Ok(serde_json::from_str::<ProblemData>(&problem_info)?)
     }
 
+    pub async fn check_submission(
+        &self,
+        submission_id: SubmissionId,
+    ) -> Result<SubmExecutionResult, Errors> {
+        judge::check_once::<SubmExecutionResult>(&self.client, &submission_id.to_string()).await
+    }
+
+    pub async fn check_interpretation(
+        &self,
+        interpret_id: &InterpretId,
+    ) -> Result<TestExecutionResult, Errors> {
+        judge::check_once::<TestExecutionResult>(&self.client, &interpret_id.to_string()).await
+    }
+
+    pub async fn submission_details(
+        &self,
+        submission_id: SubmissionId,
+    ) -> Result<SubmissionDetailsNode, Errors> {
+        let query = json!({
+            "operationName": "submissionDetails",
+            "variables": {
+                "submissionId": submission_id
+            },
+            "query": "query submissionDetails($submissionId: Int!) {\n  submissionDetails(submissionId: $submissionId) {\n    runtime\n    runtimeDisplay\n    runtimePercentile\n    runtimeDistribution\n    memory\n    memoryDisplay\n    memoryPercentile\n    memoryDistribution\n    code\n    timestamp\n    statusCode\n    user {\n      username\n    }\n    lang {\n      name\n      verboseName\n    }\n    question {\n      questionId\n      titleSlug\n    }\n    notes\n    runtimeError\n    compileError\n    lastTestcase\n    codeOutput\n    expectedOutput\n    totalCorrect\n    totalTestcases\n    stdOutput\n  }\n}"
+        });
+
+        let query = serde_json::to_string(&query)?;
+
+        let details = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        serde_json::from_str::<SubmissionDetails>(&details)?
+            .data
+            .submissionDetails
+            .ok_or_else(|| Errors::ApiError("Submission details are not available".into()))
+    }
+
+    pub fn solution_exporter(&self, dir: &str) -> SolutionExporter<'_> {
+        SolutionExporter {
+            api: self,
+            dir: PathBuf::from(dir),
+            manifest_name: String::from(".export_manifest.json"),
+        }
+    }
+
     pub fn problem_builder(&self) -> ProblemBuilder {
         ProblemBuilder {
             client: self.client.clone(),
             key_word: String::new(),
             limit: 5,
+            skip: 0,
             category: String::new(),
             filters: Filters::default(),
         }
@@ -229,69 +319,63 @@ impl UserApi {
     pub async fn my_profile(&self) -> Result<MyProfile, Errors> {
         Ok(MyProfile {
             client: self.client.clone(),
-            fav_lists: Self::fetch_fav_list_data(&self).await?,
+            fav_lists: profile::fetch_fav_lists(&self.client).await?,
         })
     }
 
-    pub fn find_profile(&self, username: &str) -> UserProfile {
-        UserProfile {
-            client: self.client.clone(),
-            username: String::from(username),
-        }
+    pub async fn upcoming_contests(&self) -> Result<Vec<UpcomingContest>, Errors> {
+        let query = json!({
+            "operationName": "upcomingContests",
+            "variables": {},
+            "query": "query upcomingContests {
+                upcomingContests {
+                    title
+                    titleSlug
+                    startTime
+                    duration
+                }
+            }"
+        });
+
+        let query = serde_json::to_string(&query)?;
+
+        let contests_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str::<UpcomingContests>(&contests_info)?
+            .data
+            .upcomingContests)
     }
 
-    async fn fetch_fav_list_data(&self) -> Result<FavoriteList, Errors> {
+    pub async fn past_contests(&self, page: u32) -> Result<PastContestsPage, Errors> {
         let query = json!({
-            "operationName": "favoritesList",
-            "variables": {},
-            "query": "query favoritesList {
-                favoritesLists {
-                    allFavorites {
-                        idHash
-                        name
-                        description
-                        viewCount
-                        creator
-                        isWatched
-                        isPublicFavorite
-                        questions {
-                            questionId
-                            status
-                            title
-                            titleSlug
-                            __typename
-                        }
-                        __typename
-                    }
-                    watchedFavorites {
-                        idHash
-                        name
-                        description
-                        viewCount
-                        creator
-                        isWatched
-                        isPublicFavorite
-                        questions {
-                            questionId
-                            status
-                            title
-                            titleSlug
-                            __typename
-                        }
-                        __typename
+            "operationName": "pastContests",
+            "variables": { "pageNo": page },
+            "query": "query pastContests($pageNo: Int) {
+                pastContests(pageNo: $pageNo) {
+                    pageNum
+                    currentPage
+                    totalNum
+                    numPerPage
+                    data {
+                        title
+                        titleSlug
+                        startTime
+                        originStartTime
                     }
-                    __typename
-                }
-                userStatus {
-                    username
-                    __typename
                 }
             }"
         });
 
         let query = serde_json::to_string(&query)?;
 
-        let list_data = self
+        let contests_info = self
             .client
             .post("https://leetcode.com/graphql/")
             .body(query)
@@ -300,11 +384,65 @@ impl UserApi {
             .text()
             .await?;
 
-        Ok(serde_json::from_str::<FavoriteList>(&list_data)?)
+        Ok(serde_json::from_str::<PastContests>(&contests_info)?
+            .data
+            .pastContests)
+    }
+
+    pub async fn contest(&self, title_slug: &str) -> Result<Contest<'_>, Errors> {
+        let contest_info = self
+            .client
+            .get(format!(
+                "https://leetcode.com/contest/api/info/{}/",
+                title_slug
+            ))
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(Contest {
+            api: self,
+            detail: serde_json::from_str::<ContestDetail>(&contest_info)?,
+        })
+    }
+
+    pub async fn contest_ranking(
+        &self,
+        title_slug: &str,
+        page: u32,
+    ) -> Result<ContestRankingPage, Errors> {
+        let ranking_info = self
+            .client
+            .get(format!(
+                "https://leetcode.com/contest/api/ranking/{}/",
+                title_slug
+            ))
+            .query(&[
+                ("pagination", page.to_string()),
+                ("region", String::from("global")),
+            ])
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str::<ContestRankingPage>(&ranking_info)?)
+    }
+
+    pub async fn favorite_list(&self, id_hash: &str) -> Result<FavoriteNode, Errors> {
+        profile::fetch_favorite_list(&self.client, id_hash).await
+    }
+
+    pub fn find_profile(&self, username: &str) -> UserProfile {
+        UserProfile {
+            client: self.client.clone(),
+            username: String::from(username),
+        }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ProgrammingLanguage {
     CPP,
     Java,
@@ -328,4 +466,254 @@ pub enum ProgrammingLanguage {
     Dart,
     Pandas,
     React,
+    MySQL,
+    MSSQL,
+    OracleSQL,
+    PostgreSQL,
+}
+
+impl ProgrammingLanguage {
+    pub const ALL: [ProgrammingLanguage; 26] = [
+        ProgrammingLanguage::CPP,
+        ProgrammingLanguage::Java,
+        ProgrammingLanguage::Python,
+        ProgrammingLanguage::Python3,
+        ProgrammingLanguage::C,
+        ProgrammingLanguage::CSharp,
+        ProgrammingLanguage::JavaScript,
+        ProgrammingLanguage::TypeScript,
+        ProgrammingLanguage::Ruby,
+        ProgrammingLanguage::Swift,
+        ProgrammingLanguage::Go,
+        ProgrammingLanguage::Bash,
+        ProgrammingLanguage::Scala,
+        ProgrammingLanguage::Kotlin,
+        ProgrammingLanguage::Rust,
+        ProgrammingLanguage::PHP,
+        ProgrammingLanguage::Racket,
+        ProgrammingLanguage::Erlang,
+        ProgrammingLanguage::Elixir,
+        ProgrammingLanguage::Dart,
+        ProgrammingLanguage::Pandas,
+        ProgrammingLanguage::React,
+        ProgrammingLanguage::MySQL,
+        ProgrammingLanguage::MSSQL,
+        ProgrammingLanguage::OracleSQL,
+        ProgrammingLanguage::PostgreSQL,
+    ];
+
+    pub fn slug(&self) -> &'static str {
+        match self {
+            ProgrammingLanguage::CPP => "cpp",
+            ProgrammingLanguage::Java => "java",
+            ProgrammingLanguage::Python => "python",
+            ProgrammingLanguage::Python3 => "python3",
+            ProgrammingLanguage::C => "c",
+            ProgrammingLanguage::CSharp => "csharp",
+            ProgrammingLanguage::JavaScript => "javascript",
+            ProgrammingLanguage::TypeScript => "typescript",
+            ProgrammingLanguage::Ruby => "ruby",
+            ProgrammingLanguage::Swift => "swift",
+            ProgrammingLanguage::Go => "golang",
+            ProgrammingLanguage::Bash => "bash",
+            ProgrammingLanguage::Scala => "scala",
+            ProgrammingLanguage::Kotlin => "kotlin",
+            ProgrammingLanguage::Rust => "rust",
+            ProgrammingLanguage::PHP => "php",
+            ProgrammingLanguage::Racket => "racket",
+            ProgrammingLanguage::Erlang => "erlang",
+            ProgrammingLanguage::Elixir => "elixir",
+            ProgrammingLanguage::Dart => "dart",
+            ProgrammingLanguage::Pandas => "pythondata",
+            ProgrammingLanguage::React => "react",
+            ProgrammingLanguage::MySQL => "mysql",
+            ProgrammingLanguage::MSSQL => "mssql",
+            ProgrammingLanguage::OracleSQL => "oraclesql",
+            ProgrammingLanguage::PostgreSQL => "postgresql",
+        }
+    }
+
+    pub fn pretty_name(&self) -> &'static str {
+        match self {
+            ProgrammingLanguage::CPP => "C++",
+            ProgrammingLanguage::Java => "Java",
+            ProgrammingLanguage::Python => "Python",
+            ProgrammingLanguage::Python3 => "Python3",
+            ProgrammingLanguage::C => "C",
+            ProgrammingLanguage::CSharp => "C#",
+            ProgrammingLanguage::JavaScript => "JavaScript",
+            ProgrammingLanguage::TypeScript => "TypeScript",
+            ProgrammingLanguage::Ruby => "Ruby",
+            ProgrammingLanguage::Swift => "Swift",
+            ProgrammingLanguage::Go => "Go",
+            ProgrammingLanguage::Bash => "Bash",
+            ProgrammingLanguage::Scala => "Scala",
+            ProgrammingLanguage::Kotlin => "Kotlin",
+            ProgrammingLanguage::Rust => "Rust",
+            ProgrammingLanguage::PHP => "PHP",
+            ProgrammingLanguage::Racket => "Racket",
+            ProgrammingLanguage::Erlang => "Erlang",
+            ProgrammingLanguage::Elixir => "Elixir",
+            ProgrammingLanguage::Dart => "Dart",
+            ProgrammingLanguage::Pandas => "Pandas",
+            ProgrammingLanguage::React => "React",
+            ProgrammingLanguage::MySQL => "MySQL",
+            ProgrammingLanguage::MSSQL => "MS SQL Server",
+            ProgrammingLanguage::OracleSQL => "Oracle",
+            ProgrammingLanguage::PostgreSQL => "PostgreSQL",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ProgrammingLanguage::CPP => "cpp",
+            ProgrammingLanguage::Java => "java",
+            ProgrammingLanguage::Python => "py",
+            ProgrammingLanguage::Python3 => "py",
+            ProgrammingLanguage::C => "c",
+            ProgrammingLanguage::CSharp => "cs",
+            ProgrammingLanguage::JavaScript => "js",
+            ProgrammingLanguage::TypeScript => "ts",
+            ProgrammingLanguage::Ruby => "rb",
+            ProgrammingLanguage::Swift => "swift",
+            ProgrammingLanguage::Go => "go",
+            ProgrammingLanguage::Bash => "sh",
+            ProgrammingLanguage::Scala => "scala",
+            ProgrammingLanguage::Kotlin => "kt",
+            ProgrammingLanguage::Rust => "rs",
+            ProgrammingLanguage::PHP => "php",
+            ProgrammingLanguage::Racket => "rkt",
+            ProgrammingLanguage::Erlang => "erl",
+            ProgrammingLanguage::Elixir => "ex",
+            ProgrammingLanguage::Dart => "dart",
+            ProgrammingLanguage::Pandas => "py",
+            ProgrammingLanguage::React => "jsx",
+            ProgrammingLanguage::MySQL => "sql",
+            ProgrammingLanguage::MSSQL => "sql",
+            ProgrammingLanguage::OracleSQL => "sql",
+            ProgrammingLanguage::PostgreSQL => "sql",
+        }
+    }
+
+    pub fn line_comment(&self) -> &'static str {
+        match self {
+            ProgrammingLanguage::CPP => "//",
+            ProgrammingLanguage::Java => "//",
+            ProgrammingLanguage::Python => "#",
+            ProgrammingLanguage::Python3 => "#",
+            ProgrammingLanguage::C => "//",
+            ProgrammingLanguage::CSharp => "//",
+            ProgrammingLanguage::JavaScript => "//",
+            ProgrammingLanguage::TypeScript => "//",
+            ProgrammingLanguage::Ruby => "#",
+            ProgrammingLanguage::Swift => "//",
+            ProgrammingLanguage::Go => "//",
+            ProgrammingLanguage::Bash => "#",
+            ProgrammingLanguage::Scala => "//",
+            ProgrammingLanguage::Kotlin => "//",
+            ProgrammingLanguage::Rust => "//",
+            ProgrammingLanguage::PHP => "//",
+            ProgrammingLanguage::Racket => ";",
+            ProgrammingLanguage::Erlang => "%",
+            ProgrammingLanguage::Elixir => "#",
+            ProgrammingLanguage::Dart => "//",
+            ProgrammingLanguage::Pandas => "#",
+            ProgrammingLanguage::React => "//",
+            ProgrammingLanguage::MySQL => "--",
+            ProgrammingLanguage::MSSQL => "--",
+            ProgrammingLanguage::OracleSQL => "--",
+            ProgrammingLanguage::PostgreSQL => "--",
+        }
+    }
+
+    pub fn block_comment(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            ProgrammingLanguage::CPP => Some(("/*", "*/")),
+            ProgrammingLanguage::Java => Some(("/*", "*/")),
+            ProgrammingLanguage::Python => None,
+            ProgrammingLanguage::Python3 => None,
+            ProgrammingLanguage::C => Some(("/*", "*/")),
+            ProgrammingLanguage::CSharp => Some(("/*", "*/")),
+            ProgrammingLanguage::JavaScript => Some(("/*", "*/")),
+            ProgrammingLanguage::TypeScript => Some(("/*", "*/")),
+            ProgrammingLanguage::Ruby => Some(("=begin", "=end")),
+            ProgrammingLanguage::Swift => Some(("/*", "*/")),
+            ProgrammingLanguage::Go => Some(("/*", "*/")),
+            ProgrammingLanguage::Bash => None,
+            ProgrammingLanguage::Scala => Some(("/*", "*/")),
+            ProgrammingLanguage::Kotlin => Some(("/*", "*/")),
+            ProgrammingLanguage::Rust => Some(("/*", "*/")),
+            ProgrammingLanguage::PHP => Some(("/*", "*/")),
+            ProgrammingLanguage::Racket => Some(("#|", "|#")),
+            ProgrammingLanguage::Erlang => None,
+            ProgrammingLanguage::Elixir => None,
+            ProgrammingLanguage::Dart => Some(("/*", "*/")),
+            ProgrammingLanguage::Pandas => None,
+            ProgrammingLanguage::React => Some(("/*", "*/")),
+            ProgrammingLanguage::MySQL => Some(("/*", "*/")),
+            ProgrammingLanguage::MSSQL => Some(("/*", "*/")),
+            ProgrammingLanguage::OracleSQL => Some(("/*", "*/")),
+            ProgrammingLanguage::PostgreSQL => Some(("/*", "*/")),
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<ProgrammingLanguage> {
+        match slug {
+            "go" => Some(ProgrammingLanguage::Go),
+            "pandas" => Some(ProgrammingLanguage::Pandas),
+            _ => Self::ALL.into_iter().find(|lang| lang.slug() == slug),
+        }
+    }
+
+    pub fn from_pretty_name(name: &str) -> Option<ProgrammingLanguage> {
+        match name {
+            "Python Data Science" => Some(ProgrammingLanguage::Pandas),
+            _ => Self::ALL
+                .into_iter()
+                .find(|lang| lang.pretty_name() == name),
+        }
+    }
+
+    pub(crate) fn from_judge_result(
+        lang: Option<&str>,
+        pretty_lang: Option<&str>,
+    ) -> Option<ProgrammingLanguage> {
+        lang.and_then(Self::from_slug)
+            .or_else(|| pretty_lang.and_then(Self::from_pretty_name))
+    }
+}
+
+impl std::fmt::Display for ProgrammingLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.pretty_name())
+    }
+}
+
+impl std::str::FromStr for ProgrammingLanguage {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::from_slug(&s.to_lowercase())
+            .or_else(|| Self::from_pretty_name(s))
+            .or_else(|| {
+                Self::ALL
+                    .into_iter()
+                    .find(|lang| lang.pretty_name().eq_ignore_ascii_case(s))
+            })
+            .ok_or_else(|| Errors::ApiError(format!("Unknown programming language `{}`", s)))
+    }
+}
+
+impl Serialize for ProgrammingLanguage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.slug())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProgrammingLanguage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let lang = String::deserialize(deserializer)?;
+        lang.parse().map_err(serde::de::Error::custom)
+    }
 }
@@ -0,0 +1,356 @@
+use std::{collections::HashSet, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Errors, resources::fav_list::FavoriteNode};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListSpec {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub is_public: bool,
+    #[serde(default)]
+    pub questions: Vec<ListQuestion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListQuestion {
+    pub title_slug: String,
+    #[serde(default)]
+    pub question_id: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub id_hash: String,
+    pub added: Vec<String>,
+    pub unresolved: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum ListOperation {
+    Create(String),
+    Update {
+        description: String,
+        is_public: bool,
+    },
+    Add(String),
+    Remove(String),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CsvRow {
+    name: String,
+    description: String,
+    is_public: bool,
+    question_id: String,
+    title_slug: String,
+}
+
+impl ListSpec {
+    pub fn new(name: &str) -> ListSpec {
+        ListSpec {
+            name: String::from(name),
+            ..Default::default()
+        }
+    }
+
+    pub fn set_description(mut self, description: &str) -> ListSpec {
+        self.description = String::from(description);
+        self
+    }
+
+    pub fn set_public(mut self, is_public: bool) -> ListSpec {
+        self.is_public = is_public;
+        self
+    }
+
+    pub fn set_questions(mut self, title_slugs: Vec<&str>) -> ListSpec {
+        self.questions = title_slugs
+            .into_iter()
+            .map(|title_slug| ListQuestion {
+                title_slug: String::from(title_slug),
+                question_id: None,
+            })
+            .collect();
+        self
+    }
+
+    pub fn to_json(&self) -> Result<String, Errors> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(data: &str) -> Result<ListSpec, Errors> {
+        Ok(serde_json::from_str::<ListSpec>(data)?)
+    }
+
+    pub fn to_csv(&self) -> Result<String, Errors> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        let row = |question_id: &str, title_slug: &str| CsvRow {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            is_public: self.is_public,
+            question_id: String::from(question_id),
+            title_slug: String::from(title_slug),
+        };
+
+        if self.questions.is_empty() {
+            writer.serialize(row("", ""))?;
+        }
+        for question in &self.questions {
+            writer.serialize(row(
+                question.question_id.as_deref().unwrap_or_default(),
+                &question.title_slug,
+            ))?;
+        }
+
+        let data = writer
+            .into_inner()
+            .map_err(|err| Errors::IoError(err.into_error()))?;
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
+
+    pub fn from_csv(data: &str) -> Result<ListSpec, Errors> {
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let mut spec: Option<ListSpec> = None;
+
+        for row in reader.deserialize::<CsvRow>() {
+            let row = row?;
+            let spec = spec.get_or_insert_with(|| {
+                ListSpec::new(&row.name)
+                    .set_description(&row.description)
+                    .set_public(row.is_public)
+            });
+            if row.name != spec.name {
+                return Err(Errors::ApiError(format!(
+                    "CSV contains more than one list: `{}` and `{}`",
+                    spec.name, row.name
+                )));
+            }
+            if !row.title_slug.is_empty() {
+                spec.questions.push(ListQuestion {
+                    title_slug: row.title_slug,
+                    question_id: Some(row.question_id).filter(|id| !id.is_empty()),
+                });
+            }
+        }
+
+        spec.ok_or_else(|| Errors::ApiError("CSV does not contain a list".into()))
+    }
+
+    pub async fn read(path: &str) -> Result<ListSpec, Errors> {
+        let data = tokio::fs::read_to_string(path).await?;
+        if is_csv(path) {
+            ListSpec::from_csv(&data)
+        } else {
+            ListSpec::from_json(&data)
+        }
+    }
+
+    pub async fn write(&self, path: &str) -> Result<(), Errors> {
+        let data = if is_csv(path) {
+            self.to_csv()?
+        } else {
+            self.to_json()?
+        };
+        tokio::fs::write(path, data).await?;
+        Ok(())
+    }
+}
+
+impl FavoriteNode {
+    pub fn to_spec(&self) -> ListSpec {
+        ListSpec {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            is_public: self.isPublicFavorite,
+            questions: self
+                .questions
+                .iter()
+                .map(|question| ListQuestion {
+                    title_slug: question.titleSlug.clone(),
+                    question_id: Some(question.questionId.clone()),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn plan_sync(current: Option<&FavoriteNode>, spec: &ListSpec) -> Vec<ListOperation> {
+    let mut operations = Vec::new();
+
+    let current_slugs = match current {
+        Some(favourite) => {
+            if favourite.isPublicFavorite != spec.is_public
+                || favourite.description != spec.description
+            {
+                operations.push(update_operation(spec));
+            }
+            favourite
+                .questions
+                .iter()
+                .map(|question| question.titleSlug.as_str())
+                .collect::<Vec<&str>>()
+        }
+        None => {
+            operations.push(ListOperation::Create(spec.name.clone()));
+            if spec.is_public || !spec.description.is_empty() {
+                operations.push(update_operation(spec));
+            }
+            Vec::new()
+        }
+    };
+
+    for title_slug in &current_slugs {
+        if !spec
+            .questions
+            .iter()
+            .any(|question| question.title_slug == *title_slug)
+        {
+            operations.push(ListOperation::Remove(String::from(*title_slug)));
+        }
+    }
+
+    let mut added = HashSet::new();
+    for question in &spec.questions {
+        let title_slug = question.title_slug.as_str();
+        if !current_slugs.contains(&title_slug) && added.insert(title_slug) {
+            operations.push(ListOperation::Add(String::from(title_slug)));
+        }
+    }
+
+    operations
+}
+
+fn update_operation(spec: &ListSpec) -> ListOperation {
+    ListOperation::Update {
+        description: spec.description.clone(),
+        is_public: spec.is_public,
+    }
+}
+
+fn is_csv(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_sync, ListOperation, ListSpec};
+    use crate::resources::fav_list::{FavoriteNode, QuestionNode};
+
+    fn favourite(description: &str, is_public: bool, title_slugs: &[&str]) -> FavoriteNode {
+        FavoriteNode {
+            idHash: String::from("abc123"),
+            name: String::from("Graphs"),
+            description: String::from(description),
+            viewCount: 0,
+            creator: String::from("me"),
+            isWatched: false,
+            isPublicFavorite: is_public,
+            questions: title_slugs
+                .iter()
+                .map(|title_slug| QuestionNode {
+                    questionId: String::new(),
+                    status: None,
+                    title: String::new(),
+                    titleSlug: String::from(*title_slug),
+                    __typename: String::from("QuestionNode"),
+                })
+                .collect(),
+            __typename: String::from("FavoriteNode"),
+        }
+    }
+
+    #[test]
+    fn plans_new_list() {
+        let spec = ListSpec::new("Graphs")
+            .set_description("BFS and DFS")
+            .set_public(true)
+            .set_questions(vec![
+                "number-of-islands",
+                "clone-graph",
+                "number-of-islands",
+            ]);
+
+        assert_eq!(
+            plan_sync(None, &spec),
+            vec![
+                ListOperation::Create(String::from("Graphs")),
+                ListOperation::Update {
+                    description: String::from("BFS and DFS"),
+                    is_public: true,
+                },
+                ListOperation::Add(String::from("number-of-islands")),
+                ListOperation::Add(String::from("clone-graph")),
+            ]
+        );
+    }
+
+    #[test]
+    fn plans_changes_to_existing_list() {
+        let current = favourite("Old", false, &["two-sum", "clone-graph"]);
+        let spec = ListSpec::new("Graphs")
+            .set_description("BFS and DFS")
+            .set_questions(vec!["clone-graph", "course-schedule"]);
+
+        assert_eq!(
+            plan_sync(Some(&current), &spec),
+            vec![
+                ListOperation::Update {
+                    description: String::from("BFS and DFS"),
+                    is_public: false,
+                },
+                ListOperation::Remove(String::from("two-sum")),
+                ListOperation::Add(String::from("course-schedule")),
+            ]
+        );
+
+        let spec = current.to_spec();
+        assert!(plan_sync(Some(&current), &spec).is_empty());
+    }
+
+    #[test]
+    fn merges_metadata_changes_into_one_update() {
+        let current = favourite("Old", false, &["two-sum"]);
+        let spec = current
+            .to_spec()
+            .set_description("BFS and DFS")
+            .set_public(true);
+
+        assert_eq!(
+            plan_sync(Some(&current), &spec),
+            vec![ListOperation::Update {
+                description: String::from("BFS and DFS"),
+                is_public: true,
+            }]
+        );
+
+        let spec = current.to_spec().set_public(true);
+        assert_eq!(
+            plan_sync(Some(&current), &spec),
+            vec![ListOperation::Update {
+                description: String::from("Old"),
+                is_public: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn spec_round_trips_through_csv_and_json() {
+        let spec = ListSpec::new("Graphs, \"hard\"")
+            .set_description("BFS\nDFS")
+            .set_public(true)
+            .set_questions(vec!["number-of-islands", "clone-graph"]);
+
+        assert_eq!(ListSpec::from_csv(&spec.to_csv().unwrap()).unwrap(), spec);
+        assert_eq!(ListSpec::from_json(&spec.to_json().unwrap()).unwrap(), spec);
+
+        let empty = ListSpec::new("Empty").set_description("nothing yet");
+        assert_eq!(ListSpec::from_csv(&empty.to_csv().unwrap()).unwrap(), empty);
+    }
+}
@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::resources::notification::NotificationNode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationKind {
+    Reply(NotificationMeta),
+    Mention(NotificationMeta),
+    Contest(NotificationMeta),
+    Badge(NotificationMeta),
+    Follow(NotificationMeta),
+    System(NotificationMeta),
+    Other {
+        type_: String,
+        meta: NotificationMeta,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NotificationMeta {
+    pub url: Option<String>,
+    pub user: Option<String>,
+    pub title: Option<String>,
+    pub raw: Value,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WatchState {
+    pub last_modified: i64,
+    pub num_unread: i32,
+    pub last_seen_date: i64,
+    pub seen_ids: Vec<i32>,
+}
+
+impl WatchState {
+    pub(crate) fn is_unseen(&self, notification: &NotificationNode) -> bool {
+        notification.modifiedDate > self.last_seen_date
+            || (notification.modifiedDate == self.last_seen_date
+                && !self.seen_ids.contains(&notification.notificationId))
+    }
+
+    pub(crate) fn mark_seen(&mut self, notification: &NotificationNode) {
+        if notification.modifiedDate > self.last_seen_date {
+            self.last_seen_date = notification.modifiedDate;
+            self.seen_ids.clear();
+        }
+        if notification.modifiedDate == self.last_seen_date {
+            self.seen_ids.push(notification.notificationId);
+        }
+    }
+}
+
+impl NotificationKind {
+    pub fn from_parts(type_: &str, metadata: &str) -> NotificationKind {
+        let meta = NotificationMeta::parse(metadata);
+        let kind = type_.to_ascii_uppercase();
+
+        if kind.contains("REPLY") || kind.contains("COMMENT") {
+            NotificationKind::Reply(meta)
+        } else if kind.contains("MENTION") {
+            NotificationKind::Mention(meta)
+        } else if kind.contains("CONTEST") {
+            NotificationKind::Contest(meta)
+        } else if kind.contains("BADGE") {
+            NotificationKind::Badge(meta)
+        } else if kind.contains("FOLLOW") {
+            NotificationKind::Follow(meta)
+        } else if kind.contains("SYSTEM") || kind.contains("ANNOUNCEMENT") {
+            NotificationKind::System(meta)
+        } else {
+            NotificationKind::Other {
+                type_: String::from(type_),
+                meta,
+            }
+        }
+    }
+
+    pub fn meta(&self) -> &NotificationMeta {
+        match self {
+            NotificationKind::Reply(meta)
+            | NotificationKind::Mention(meta)
+            | NotificationKind::Contest(meta)
+            | NotificationKind::Badge(meta)
+            | NotificationKind::Follow(meta)
+            | NotificationKind::System(meta)
+            | NotificationKind::Other { meta, .. } => meta,
+        }
+    }
+}
+
+impl NotificationMeta {
+    fn parse(metadata: &str) -> NotificationMeta {
+        let raw = serde_json::from_str::<Value>(metadata).unwrap_or(Value::Null);
+        NotificationMeta {
+            url: find_string(&raw, &["url", "link", "questionUrl", "topicUrl"]),
+            user: find_string(&raw, &["username", "user", "fromUser", "actor", "sender"]),
+            title: find_string(&raw, &["title", "contestTitle", "badgeName", "name"]),
+            raw,
+        }
+    }
+}
+
+impl NotificationNode {
+    pub fn kind(&self) -> NotificationKind {
+        NotificationKind::from_parts(
+            &self.notificationData.type_,
+            &self.notificationData.metadata,
+        )
+    }
+}
+
+fn find_string(raw: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match raw.get(key)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Object(object) => object
+            .get("username")
+            .or_else(|| object.get("name"))
+            .and_then(Value::as_str)
+            .map(String::from),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{NotificationKind, NotificationMeta, WatchState};
+    use crate::resources::notification::{NotificationDataNode, NotificationNode};
+
+    fn notification(notification_id: i32, modified_date: i64) -> NotificationNode {
+        NotificationNode {
+            id: notification_id.to_string(),
+            notificationId: notification_id,
+            modifiedDate: modified_date,
+            actioned: false,
+            notificationData: NotificationDataNode {
+                id: notification_id.to_string(),
+                content: String::new(),
+                type_: String::from("SYSTEM_MESSAGE"),
+                metadata: String::from("{}"),
+                __typename: String::from("NotificationDataNode"),
+            },
+            __typename: String::from("NotificationNode"),
+        }
+    }
+
+    #[test]
+    fn classifies_notification_types() {
+        let kind = |type_: &str| NotificationKind::from_parts(type_, "{}");
+        let meta = NotificationMeta {
+            raw: json!({}),
+            ..Default::default()
+        };
+
+        assert_eq!(kind("DISCUSS_REPLY"), NotificationKind::Reply(meta.clone()));
+        assert_eq!(kind("new_comment"), NotificationKind::Reply(meta.clone()));
+        assert_eq!(kind("AT_MENTION"), NotificationKind::Mention(meta.clone()));
+        assert_eq!(
+            kind("CONTEST_REMINDER"),
+            NotificationKind::Contest(meta.clone())
+        );
+        assert_eq!(kind("BADGE_EARNED"), NotificationKind::Badge(meta.clone()));
+        assert_eq!(kind("NEW_FOLLOWER"), NotificationKind::Follow(meta.clone()));
+        assert_eq!(
+            kind("SYSTEM_MESSAGE"),
+            NotificationKind::System(meta.clone())
+        );
+        assert_eq!(kind("ANNOUNCEMENT"), NotificationKind::System(meta.clone()));
+        assert_eq!(
+            kind("COMMENT_MENTION"),
+            NotificationKind::Reply(meta.clone())
+        );
+        assert_eq!(
+            kind("Weird_Type"),
+            NotificationKind::Other {
+                type_: String::from("Weird_Type"),
+                meta,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_notification_meta() {
+        let kind = NotificationKind::from_parts(
+            "DISCUSS_REPLY",
+            r#"{"topicUrl": "/discuss/1", "fromUser": {"username": "alice"}, "title": "Two Sum"}"#,
+        );
+        let meta = kind.meta();
+        assert_eq!(meta.url.as_deref(), Some("/discuss/1"));
+        assert_eq!(meta.user.as_deref(), Some("alice"));
+        assert_eq!(meta.title.as_deref(), Some("Two Sum"));
+        assert_eq!(meta.raw["fromUser"]["username"], "alice");
+
+        let kind =
+            NotificationKind::from_parts("BADGE_EARNED", r#"{"badgeName": 7, "name": "Knight"}"#);
+        assert_eq!(kind.meta().title.as_deref(), Some("Knight"));
+
+        let kind = NotificationKind::from_parts("BADGE_EARNED", "not json");
+        assert_eq!(kind.meta(), &NotificationMeta::default());
+    }
+
+    #[test]
+    fn tracks_seen_ids_per_last_seen_date() {
+        let mut state = WatchState::default();
+        let first = notification(1, 100);
+        let same_date = notification(2, 100);
+        let newer = notification(3, 200);
+        let older = notification(4, 50);
+
+        assert!(state.is_unseen(&first));
+        state.mark_seen(&first);
+        assert_eq!(state.last_seen_date, 100);
+        assert_eq!(state.seen_ids, vec![1]);
+        assert!(!state.is_unseen(&first));
+        assert!(state.is_unseen(&same_date));
+
+        state.mark_seen(&same_date);
+        assert_eq!(state.seen_ids, vec![1, 2]);
+
+        assert!(state.is_unseen(&newer));
+        state.mark_seen(&newer);
+        assert_eq!(state.last_seen_date, 200);
+        assert_eq!(state.seen_ids, vec![3]);
+        assert!(!state.is_unseen(&first));
+
+        assert!(!state.is_unseen(&older));
+        state.mark_seen(&older);
+        assert_eq!(state.last_seen_date, 200);
+        assert_eq!(state.seen_ids, vec![3]);
+    }
+}
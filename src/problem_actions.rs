@@ -1,18 +1,26 @@
-use std::time::Duration;
+use std::{collections::VecDeque, path::Path};
 
+use futures::{stream, Stream, StreamExt};
 use serde_json::json;
 
 use crate::{
+    assets,
     error::Errors,
+    harness::{self, TestHarness},
+    judge::{self, JudgeEvent, PollConfig},
+    render,
     resources::{
+        meta_data::MetaData,
         problemfulldata::{
             CodeSnippetNode, ProblemFullData, SimilarQuestions, Solution, Statistics, TopicTagNode,
         },
-        subm_send::{SubmExecutionResult, SubmissionCase, SubmissionCaseResp},
-        subm_show::SubmList,
-        test_send::{TestCase, TestCaseResp, TestExecutionResult},
-        Description, Rate,
+        subm_send::{SubmExecutionResult, SubmissionCase, SubmissionCaseResp, SubmissionId},
+        subm_show::{SubmList, SubmissionDumpNode},
+        test_send::{InterpretId, TestCase, TestCaseResp, TestExecutionResult},
+        Description, DescriptionSections, Rate,
     },
+    scaffold::{self, RustScaffold},
+    submissions::SubmissionFilter,
     ProgrammingLanguage,
 };
 
@@ -21,15 +29,36 @@ pub struct Problem {
     pub(crate) client: reqwest::Client,
     pub(crate) task_search_name: String,
     pub full_data: ProblemFullData,
+    pub(crate) poll_config: PollConfig,
 }
 
 impl Problem {
+    pub fn set_poll_config(mut self, poll_config: PollConfig) -> Problem {
+        self.poll_config = poll_config;
+        self
+    }
+
     pub async fn send_test(
         &self,
         lang: ProgrammingLanguage,
         typed_code: &str,
     ) -> Result<TestExecutionResult, Errors> {
-        let lang = Self::lang_converter(lang);
+        let interpret_id = self.test_async(lang, typed_code).await?;
+
+        judge::poll_check::<TestExecutionResult>(
+            &self.client,
+            &interpret_id.to_string(),
+            &self.poll_config,
+        )
+        .await
+    }
+
+    pub async fn test_async(
+        &self,
+        lang: ProgrammingLanguage,
+        typed_code: &str,
+    ) -> Result<InterpretId, Errors> {
+        let lang = self.supported_lang_slug(lang)?;
         let json_data = serde_json::to_string(&TestCase {
             question_id: self.full_data.data.question.questionId.clone(),
             data_input: self.full_data.data.question.sampleTestCase.clone(),
@@ -38,7 +67,7 @@ impl Problem {
             typed_code: String::from(typed_code),
         })?;
 
-        let resp = self
+        Ok(self
             .client
             .post(format!(
                 "https://leetcode.com/problems/{}/interpret_solution/",
@@ -48,29 +77,8 @@ impl Problem {
             .send()
             .await?
             .json::<TestCaseResp>()
-            .await?;
-
-        loop {
-            let status = self
-                .client
-                .get(format!(
-                    "https://leetcode.com/submissions/detail/{}/check/",
-                    resp.interpret_id
-                ))
-                .send()
-                .await?
-                .json::<TestExecutionResult>()
-                .await?;
-            if status.state == "SUCCESS" {
-                return Ok(status);
-            } else if status.state == "FAILURE" {
-                return Err(Errors::SendError(
-                    "Sent code failure. Err may be occured by Unsupported lang for this problem"
-                        .into(),
-                ));
-            }
-            tokio::time::sleep(Duration::from_secs(1)).await;
-        }
+            .await?
+            .interpret_id)
     }
 
     pub async fn send_subm(
@@ -78,14 +86,48 @@ impl Problem {
         lang: ProgrammingLanguage,
         code: &str,
     ) -> Result<SubmExecutionResult, Errors> {
-        let lang = Self::lang_converter(lang);
+        let submission_id = self.submit_async(lang, code).await?;
+
+        judge::poll_check::<SubmExecutionResult>(
+            &self.client,
+            &submission_id.to_string(),
+            &self.poll_config,
+        )
+        .await
+    }
+
+    pub fn submit_stream(
+        &self,
+        lang: ProgrammingLanguage,
+        code: &str,
+    ) -> impl Stream<Item = JudgeEvent> + '_ {
+        let code = String::from(code);
+        stream::once(async move { self.submit_async(lang, &code).await }).flat_map(move |resp| {
+            match resp {
+                Ok(submission_id) => judge::event_stream(
+                    self.client.clone(),
+                    submission_id.to_string(),
+                    self.poll_config.clone(),
+                )
+                .left_stream(),
+                Err(err) => stream::once(async { JudgeEvent::Failed(err) }).right_stream(),
+            }
+        })
+    }
+
+    pub async fn submit_async(
+        &self,
+        lang: ProgrammingLanguage,
+        code: &str,
+    ) -> Result<SubmissionId, Errors> {
+        let lang = self.supported_lang_slug(lang)?;
         let json_data = serde_json::to_string(&SubmissionCase {
             question_id: self.full_data.data.question.questionId.clone(),
             lang: lang.to_owned(),
             typed_code: String::from(code),
         })?;
 
-        let resp = self
+        Ok(self
             .client
             .post(format!(
                 "https://leetcode.com/problems/{}/submit/",
@@ -95,61 +137,50 @@ impl Problem {
             .send()
             .await?
             .json::<SubmissionCaseResp>()
-            .await?;
-
-        loop {
-            let status = self
-                .client
-                .get(format!(
-                    "https://leetcode.com/submissions/detail/{}/check/",
-                    resp.submission_id
-                ))
-                .send()
-                .await?
-                .json::<SubmExecutionResult>()
-                .await?;
-            if status.state == "SUCCESS" {
-                return Ok(status);
-            } else if status.state == "FAILURE" {
-                return Err(Errors::SendError(
-                    "Sent code failure. Err may be occured by Unsupported lang for this problem"
-                        .into(),
-                ));
-            }
-            tokio::time::sleep(Duration::from_secs(1)).await;
-        }
+            .await?
+            .submission_id)
     }
 
-    fn lang_converter(lang: ProgrammingLanguage) -> &'static str {
-        match lang {
-            ProgrammingLanguage::CPP => "cpp",
-            ProgrammingLanguage::Java => "java",
-            ProgrammingLanguage::Python => "python",
-            ProgrammingLanguage::Python3 => "python3",
-            ProgrammingLanguage::C => "c",
-            ProgrammingLanguage::CSharp => "csharp",
-            ProgrammingLanguage::JavaScript => "javascript",
-            ProgrammingLanguage::TypeScript => "typescript",
-            ProgrammingLanguage::Ruby => "ruby",
-            ProgrammingLanguage::Swift => "swift",
-            ProgrammingLanguage::Go => "go",
-            ProgrammingLanguage::Bash => "bash",
-            ProgrammingLanguage::Scala => "scala",
-            ProgrammingLanguage::Kotlin => "kotlin",
-            ProgrammingLanguage::Rust => "rust",
-            ProgrammingLanguage::PHP => "php",
-            ProgrammingLanguage::Racket => "racket",
-            ProgrammingLanguage::Erlang => "erlang",
-            ProgrammingLanguage::Elixir => "elixir",
-            ProgrammingLanguage::Dart => "dart",
-            ProgrammingLanguage::Pandas => "pandas",
-            ProgrammingLanguage::React => "react",
+    fn supported_lang_slug(&self, lang: ProgrammingLanguage) -> Result<&'static str, Errors> {
+        let slug = lang.slug();
+        if let Some(snippets) = &self.full_data.data.question.codeSnippets {
+            if !snippets.iter().any(|snippet| snippet.langSlug == slug) {
+                return Err(Errors::SendError(format!(
+                    "Lang `{}` is not supported for this problem",
+                    slug
+                )));
+            }
         }
+        Ok(slug)
     }
+
     pub fn code_snippets(&self) -> Option<Vec<CodeSnippetNode>> {
         self.full_data.data.question.codeSnippets.clone()
     }
 
+    pub fn snippet(&self, lang: ProgrammingLanguage) -> Option<String> {
+        let slug = lang.slug();
+        self.full_data
+            .data
+            .question
+            .codeSnippets
+            .as_ref()?
+            .iter()
+            .find(|snippet| snippet.langSlug == slug)
+            .map(|snippet| snippet.code.clone())
+    }
+
+    pub fn supported_languages(&self) -> Vec<ProgrammingLanguage> {
+        self.full_data
+            .data
+            .question
+            .codeSnippets
+            .iter()
+            .flatten()
+            .filter_map(|snippet| ProgrammingLanguage::from_slug(&snippet.langSlug))
+            .collect()
+    }
+
     pub fn solution_info(&self) -> Option<Solution> {
         self.full_data.data.question.solution.clone()
     }
@@ -169,6 +200,40 @@ impl Problem {
         )?)
     }
 
+    pub fn meta_data(&self) -> Result<MetaData, Errors> {
+        Ok(serde_json::from_str::<MetaData>(
+            self.full_data.data.question.metaData.as_str(),
+        )?)
+    }
+
+    pub fn example_inputs(&self) -> Result<Vec<Vec<String>>, Errors> {
+        let meta_data = self.meta_data()?;
+        let chunk = if meta_data.is_system_design() {
+            2
+        } else {
+            meta_data.params.map_or(1, |params| params.len().max(1))
+        };
+
+        let lines = self
+            .full_data
+            .data
+            .question
+            .exampleTestcases
+            .lines()
+            .map(String::from)
+            .collect::<Vec<String>>();
+
+        Ok(lines.chunks(chunk).map(|input| input.to_vec()).collect())
+    }
+
+    pub fn rust_scaffold(&self) -> Result<RustScaffold, Errors> {
+        scaffold::rust_scaffold(self)
+    }
+
+    pub fn test_harness(&self, lang: ProgrammingLanguage) -> Result<TestHarness, Errors> {
+        harness::test_harness(self, lang)
+    }
+
     pub fn hints(&self) -> Vec<String> {
         self.full_data.data.question.hints.clone()
     }
@@ -181,6 +246,30 @@ impl Problem {
         Ok(serde_json::from_value::<Description>(descryption)?)
     }
 
+    pub fn description_sections(&self) -> DescriptionSections {
+        render::split_sections(
+            self.full_data
+                .data
+                .question
+                .content
+                .as_deref()
+                .unwrap_or_default(),
+        )
+    }
+
+    pub async fn download_images(&self, dir: &str) -> Result<String, Errors> {
+        assets::download_images(
+            self.full_data
+                .data
+                .question
+                .content
+                .as_deref()
+                .unwrap_or_default(),
+            Path::new(dir),
+        )
+        .await
+    }
+
     pub fn difficulty(&self) -> String {
         self.full_data.data.question.difficulty.clone()
     }
@@ -199,12 +288,79 @@ impl Problem {
     }
 
     pub async fn my_submissions(&self) -> Result<SubmList, Errors> {
+        self.fetch_submissions(0, 10, None).await
+    }
+
+    pub fn submission_history(
+        &self,
+        filter: SubmissionFilter,
+    ) -> impl Stream<Item = Result<SubmissionDumpNode, Errors>> + '_ {
+        let state = HistoryState {
+            offset: 0,
+            last_key: None,
+            has_next: true,
+            buffer: VecDeque::new(),
+        };
+
+        stream::unfold(state, move |mut state| {
+            let filter = filter.clone();
+            async move {
+                loop {
+                    if let Some(subm) = state.buffer.pop_front() {
+                        let timestamp = match subm.timestamp.parse::<i64>() {
+                            Ok(timestamp) => timestamp,
+                            Err(_) => {
+                                let err = Errors::ApiError(format!(
+                                    "Invalid timestamp `{}` for submission {}",
+                                    subm.timestamp, subm.id
+                                ));
+                                return Some((Err(err), state));
+                            }
+                        };
+                        if filter.is_older_than_range(timestamp) {
+                            return None;
+                        }
+                        if filter.matches(&subm.statusDisplay, &subm.lang, timestamp) {
+                            return Some((Ok(subm), state));
+                        }
+                        continue;
+                    }
+                    if !state.has_next {
+                        return None;
+                    }
+
+                    let page = match self
+                        .fetch_submissions(state.offset, HISTORY_PAGE_LIMIT, state.last_key.clone())
+                        .await
+                    {
+                        Ok(page) => page.data.submissionList,
+                        Err(err) => {
+                            state.has_next = false;
+                            return Some((Err(err), state));
+                        }
+                    };
+
+                    state.offset += HISTORY_PAGE_LIMIT;
+                    state.has_next = page.hasNext && page.lastKey.is_some();
+                    state.last_key = page.lastKey;
+                    state.buffer.extend(page.submissions);
+                }
+            }
+        })
+    }
+
+    async fn fetch_submissions(
+        &self,
+        offset: u32,
+        limit: u32,
+        last_key: Option<String>,
+    ) -> Result<SubmList, Errors> {
         let query = json!({
             "operationName": "Submissions",
             "variables": {
-                "offset": 0,
-                "limit": 10,
-                "lastKey": null,
+                "offset": offset,
+                "limit": limit,
+                "lastKey": last_key,
                 "questionSlug": self.task_search_name
             },
             "query": "query Submissions($offset: Int!, $limit: Int!, $lastKey: String, $questionSlug: String!) {\n  submissionList(offset: $offset, limit: $limit, lastKey: $lastKey, questionSlug: $questionSlug) {\n    lastKey\n    hasNext\n    submissions {\n      id\n      statusDisplay\n      lang\n      runtime\n      timestamp\n      url\n      isPending\n      memory\n      __typename\n    }\n    __typename\n  }\n}\n"
@@ -222,3 +378,12 @@ impl Problem {
             .await?)
     }
 }
+
+const HISTORY_PAGE_LIMIT: u32 = 20;
+
+struct HistoryState {
+    offset: u32,
+    last_key: Option<String>,
+    has_next: bool,
+    buffer: VecDeque<SubmissionDumpNode>,
+}
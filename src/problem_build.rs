@@ -8,6 +8,7 @@ pub struct ProblemBuilder {
     pub(crate) client: reqwest::Client,
     pub(crate) key_word: String,
     pub(crate) limit: u32,
+    pub(crate) skip: u32,
     pub(crate) category: String,
     pub(crate) filters: Filters,
 }
@@ -70,6 +71,11 @@ impl ProblemBuilder {
         self
     }
 
+    pub fn set_skip(mut self, skip: u32) -> ProblemBuilder {
+        self.skip = skip;
+        self
+    }
+
     pub fn set_keyword(mut self, keyword: &str) -> ProblemBuilder {
         self.key_word = String::from(keyword);
         self
@@ -216,7 +222,7 @@ impl ProblemBuilder {
             "#,
             "variables": {
                 "categorySlug": self.category,
-                "skip": 0,
+                "skip": self.skip,
                 "limit": self.limit,
                 "filters": filters
             },
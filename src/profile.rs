@@ -1,14 +1,55 @@
+use std::{collections::VecDeque, path::PathBuf, time::Duration};
+
+use futures::{stream, Stream};
 use serde_json::json;
 
 use crate::{
+    contest::{self, ContestResult},
     error::Errors,
+    lists::{self, ImportReport, ListOperation, ListSpec},
+    notifications::WatchState,
+    problem_actions::Problem,
     resources::{
-        beat_stats::BeatStats, data_profile::ProfileData, fav_list::FavoriteList,
-        lang_stats::LanguageStats, notification::NotificationsData,
-        pub_data_profile::UserFoundData, skill_stats::SkillStats, subm_list::RecentSubmList,
+        beat_stats::BeatStats,
+        contest::{UserContest, UserContestData, UserContestRanking},
+        data_profile::ProfileData,
+        fav_action::{FavoriteAction, QuestionId},
+        fav_detail::{FavoriteDetail, PublicFavorites},
+        fav_list::{FavoriteList, FavoriteNode},
+        lang_stats::LanguageStats,
+        notification::{NotificationAction, NotificationNode, NotificationsData},
+        pub_data_profile::UserFoundData,
+        skill_stats::SkillStats,
+        subm_dump::{SubmissionDump, SubmissionsDumpPage},
+        subm_list::RecentSubmList,
     },
+    submissions::SubmissionFilter,
 };
 
+#[derive(Debug, Clone, Copy)]
+pub enum QuestionRef<'a> {
+    Problem(&'a Problem),
+    Slug(&'a str),
+}
+
+impl<'a> From<&'a Problem> for QuestionRef<'a> {
+    fn from(problem: &'a Problem) -> Self {
+        QuestionRef::Problem(problem)
+    }
+}
+
+impl<'a> From<&'a str> for QuestionRef<'a> {
+    fn from(slug: &'a str) -> Self {
+        QuestionRef::Slug(slug)
+    }
+}
+
+impl<'a> From<&'a String> for QuestionRef<'a> {
+    fn from(slug: &'a String) -> Self {
+        QuestionRef::Slug(slug)
+    }
+}
+
 #[derive(Debug)]
 pub struct MyProfile {
     pub(crate) client: reqwest::Client,
@@ -16,84 +57,288 @@ pub struct MyProfile {
 }
 
 impl MyProfile {
-    pub async fn create_list(&self, list_name: &str) -> Result<&MyProfile, Errors> {
+    pub async fn create_list(&mut self, list_name: &str) -> Result<String, Errors> {
         let query = json!({ "name": list_name });
 
         let query = serde_json::to_string(&query)?;
 
+        let known_lists = self
+            .fav_lists
+            .data
+            .favoritesLists
+            .allFavorites
+            .iter()
+            .map(|favourite| favourite.idHash.clone())
+            .collect::<Vec<String>>();
+
         self.client
-            .get("https://leetcode.com/list/api/")
+            .post("https://leetcode.com/list/api/")
             .body(query)
             .send()
             .await?;
-        Ok(self)
+        self.refresh_lists().await?;
+
+        self.fav_lists
+            .data
+            .favoritesLists
+            .allFavorites
+            .iter()
+            .find(|favourite| {
+                favourite.name == list_name && !known_lists.contains(&favourite.idHash)
+            })
+            .map(|favourite| favourite.idHash.clone())
+            .ok_or_else(|| Errors::ApiError(format!("List `{}` was not created", list_name)))
     }
+
     pub fn show_lists(&self) -> FavoriteList {
         self.fav_lists.clone()
     }
 
+    pub async fn refresh_lists(&mut self) -> Result<&MyProfile, Errors> {
+        self.fav_lists = fetch_fav_lists(&self.client).await?;
+        Ok(self)
+    }
+
     pub async fn rename_list(
-        &self,
+        &mut self,
         prev_list_name: &str,
         new_list_name: &str,
     ) -> Result<&MyProfile, Errors> {
-        let id_hash = if let Some(id) = Self::get_id_hash(&self, prev_list_name) {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, prev_list_name) {
             id
         } else {
             return Err(Errors::ApiError("Provided name doesn't found".into()));
         };
-        let query = json!({
-            "favorite_id_hash": id_hash.0,
-            "is_public_favorite": id_hash.1,
-            "name": new_list_name
-        });
-
-        let query = serde_json::to_string(&query)?;
+        let description = self.list_description(&id_hash.0);
+        self.update_list(&id_hash.0, new_list_name, &description, id_hash.1)
+            .await?;
+        self.refresh_lists().await
+    }
 
-        self.client
-            .put("https://leetcode.com/list/api/")
-            .body(query)
-            .send()
+    pub async fn set_public(&mut self, list_name: &str) -> Result<&MyProfile, Errors> {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, list_name) {
+            id
+        } else {
+            return Err(Errors::ApiError(
+                "Provided name doesn't found in lists".into(),
+            ));
+        };
+        let description = self.list_description(&id_hash.0);
+        self.update_list(&id_hash.0, list_name, &description, true)
             .await?;
-        Ok(self)
+        self.refresh_lists().await
     }
 
-    pub async fn set_public(&self, list_name: &str) -> Result<&MyProfile, Errors> {
-        let id_hash = if let Some(id) = Self::get_id_hash(&self, list_name) {
+    pub async fn set_private(&mut self, list_name: &str) -> Result<&MyProfile, Errors> {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, list_name) {
             id
         } else {
             return Err(Errors::ApiError(
                 "Provided name doesn't found in lists".into(),
             ));
         };
-        let query = json!({
-            "favorite_id_hash": id_hash.0,
-            "is_public_favorite": true,
-            "name": list_name
-        });
+        let description = self.list_description(&id_hash.0);
+        self.update_list(&id_hash.0, list_name, &description, false)
+            .await?;
+        self.refresh_lists().await
+    }
 
-        let query = serde_json::to_string(&query)?;
+    pub async fn get_share_url(&self, list_name: &str) -> Result<String, Errors> {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, list_name) {
+            id
+        } else {
+            return Err(Errors::ApiError(
+                "Provided name doesn't found in lists".into(),
+            ));
+        };
 
-        self.client
-            .put("https://leetcode.com/list/api/")
-            .body(query)
-            .send()
-            .await?;
-        Ok(self)
+        Ok(format!("https://leetcode.com/list/{}", id_hash.0))
     }
 
-    pub async fn set_private(&self, list_name: &str) -> Result<&MyProfile, Errors> {
-        let id_hash = if let Some(id) = Self::get_id_hash(&self, list_name) {
+    pub async fn delete_list(&mut self, list_name: &str) -> Result<&MyProfile, Errors> {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, list_name) {
             id
         } else {
             return Err(Errors::ApiError(
                 "Provided name doesn't found in lists".into(),
             ));
         };
+
+        self.client
+            .delete(format!("https://leetcode.com/list/api/{}", id_hash.0))
+            .send()
+            .await?;
+
+        self.refresh_lists().await
+    }
+
+    pub fn export_list(&self, list_name: &str) -> Result<ListSpec, Errors> {
+        self.fav_lists
+            .data
+            .favoritesLists
+            .allFavorites
+            .iter()
+            .find(|favourite| favourite.name == list_name)
+            .map(|favourite| favourite.to_spec())
+            .ok_or_else(|| Errors::ApiError("Provided name doesn't found in lists".into()))
+    }
+
+    pub async fn import_list(&mut self, spec: &ListSpec) -> Result<ImportReport, Errors> {
+        let id_hash = self.create_list(&spec.name).await?;
+        if spec.is_public || !spec.description.is_empty() {
+            self.update_list(&id_hash, &spec.name, &spec.description, spec.is_public)
+                .await?;
+        }
+
+        let mut report = ImportReport {
+            id_hash,
+            ..Default::default()
+        };
+        for question in &spec.questions {
+            let title_slug = question.title_slug.clone();
+            let question_id = match self.find_question_id(&title_slug).await? {
+                Some(question_id) => question_id,
+                None => {
+                    report.unresolved.push(title_slug);
+                    continue;
+                }
+            };
+            match self
+                .send_list_mutation("addQuestionToFavorite", &report.id_hash, &question_id)
+                .await
+            {
+                Ok(()) => report.added.push(title_slug),
+                Err(Errors::ApiError(_)) => report.unresolved.push(title_slug),
+                Err(err) => return Err(err),
+            }
+        }
+
+        self.refresh_lists().await?;
+        Ok(report)
+    }
+
+    pub async fn sync_list(
+        &mut self,
+        spec: &ListSpec,
+        dry_run: bool,
+    ) -> Result<Vec<ListOperation>, Errors> {
+        let current = self
+            .fav_lists
+            .data
+            .favoritesLists
+            .allFavorites
+            .iter()
+            .find(|favourite| favourite.name == spec.name)
+            .cloned();
+
+        let operations = lists::plan_sync(current.as_ref(), spec);
+        if dry_run || operations.is_empty() {
+            return Ok(operations);
+        }
+
+        let mut id_hash = current
+            .as_ref()
+            .map(|favourite| favourite.idHash.clone())
+            .unwrap_or_default();
+        for operation in &operations {
+            match operation {
+                ListOperation::Create(name) => id_hash = self.create_list(name).await?,
+                ListOperation::Update {
+                    description,
+                    is_public,
+                } => {
+                    self.update_list(&id_hash, &spec.name, description, *is_public)
+                        .await?
+                }
+                ListOperation::Add(title_slug) => {
+                    let question_id = self.question_id(QuestionRef::Slug(title_slug)).await?;
+                    self.send_list_mutation("addQuestionToFavorite", &id_hash, &question_id)
+                        .await?
+                }
+                ListOperation::Remove(title_slug) => {
+                    let question_id = current
+                        .iter()
+                        .flat_map(|favourite| &favourite.questions)
+                        .find(|question| &question.titleSlug == title_slug)
+                        .map(|question| question.questionId.clone());
+                    let question_id = match question_id {
+                        Some(question_id) => question_id,
+                        None => self.question_id(QuestionRef::Slug(title_slug)).await?,
+                    };
+                    self.send_list_mutation("removeQuestionFromFavorite", &id_hash, &question_id)
+                        .await?
+                }
+            }
+        }
+
+        self.refresh_lists().await?;
+        Ok(operations)
+    }
+
+    pub async fn watch_list(&mut self, id_hash: &str) -> Result<&MyProfile, Errors> {
+        self.send_watch_mutation("addFavoriteToMyWatchList", id_hash)
+            .await?;
+        self.refresh_lists().await
+    }
+
+    pub async fn unwatch_list(&mut self, id_hash: &str) -> Result<&MyProfile, Errors> {
+        self.send_watch_mutation("removeFavoriteFromMyWatchList", id_hash)
+            .await?;
+        self.refresh_lists().await
+    }
+
+    async fn send_watch_mutation(&self, mutation: &str, id_hash: &str) -> Result<(), Errors> {
+        let query = format!(
+            "mutation {0}($favoriteIdHash: String!) {{
+                result: {0}(favoriteIdHash: $favoriteIdHash) {{
+                    ok
+                    error
+                }}
+            }}",
+            mutation
+        );
+
+        let json_data = json!({
+            "operationName": mutation,
+            "variables": { "favoriteIdHash": id_hash },
+            "query": query,
+        });
+
+        let query = serde_json::to_string(&json_data)?;
+
+        let action_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        let result = serde_json::from_str::<FavoriteAction>(&action_info)?
+            .data
+            .result;
+        if !result.ok {
+            return Err(Errors::ApiError(result.error.unwrap_or(format!(
+                "Can not change watch state of list `{}`",
+                id_hash
+            ))));
+        }
+        Ok(())
+    }
+
+    async fn update_list(
+        &self,
+        id_hash: &str,
+        name: &str,
+        description: &str,
+        is_public: bool,
+    ) -> Result<(), Errors> {
         let query = json!({
-            "favorite_id_hash": id_hash.0,
-            "is_public_favorite": true,
-            "name": list_name
+            "favorite_id_hash": id_hash,
+            "is_public_favorite": is_public,
+            "name": name,
+            "description": description
         });
 
         let query = serde_json::to_string(&query)?;
@@ -103,11 +348,59 @@ impl MyProfile {
             .body(query)
             .send()
             .await?;
-        Ok(self)
+        Ok(())
     }
 
-    pub async fn get_share_url(&self, list_name: &str) -> Result<String, Errors> {
-        let id_hash = if let Some(id) = Self::get_id_hash(&self, list_name) {
+    pub async fn add_to_list<'a>(
+        &mut self,
+        list_name: &str,
+        question: impl Into<QuestionRef<'a>>,
+    ) -> Result<&MyProfile, Errors> {
+        self.add_all_to_list(list_name, [question]).await
+    }
+
+    pub async fn add_all_to_list<'a, Q>(
+        &mut self,
+        list_name: &str,
+        questions: impl IntoIterator<Item = Q>,
+    ) -> Result<&MyProfile, Errors>
+    where
+        Q: Into<QuestionRef<'a>>,
+    {
+        self.change_list_questions(list_name, questions, "addQuestionToFavorite")
+            .await
+    }
+
+    pub async fn remove_from_list<'a>(
+        &mut self,
+        list_name: &str,
+        question: impl Into<QuestionRef<'a>>,
+    ) -> Result<&MyProfile, Errors> {
+        self.remove_all_from_list(list_name, [question]).await
+    }
+
+    pub async fn remove_all_from_list<'a, Q>(
+        &mut self,
+        list_name: &str,
+        questions: impl IntoIterator<Item = Q>,
+    ) -> Result<&MyProfile, Errors>
+    where
+        Q: Into<QuestionRef<'a>>,
+    {
+        self.change_list_questions(list_name, questions, "removeQuestionFromFavorite")
+            .await
+    }
+
+    async fn change_list_questions<'a, Q>(
+        &mut self,
+        list_name: &str,
+        questions: impl IntoIterator<Item = Q>,
+        mutation: &str,
+    ) -> Result<&MyProfile, Errors>
+    where
+        Q: Into<QuestionRef<'a>>,
+    {
+        let id_hash = if let Some(id) = Self::get_id_hash(self, list_name) {
             id
         } else {
             return Err(Errors::ApiError(
@@ -115,38 +408,358 @@ impl MyProfile {
             ));
         };
 
-        Ok(format!("https://leetcode.com/list/{}", id_hash.0))
+        let mut question_ids = Vec::new();
+        for question in questions {
+            question_ids.push(self.question_id(question.into()).await?);
+        }
+
+        let mut applied = Vec::new();
+        for question_id in question_ids {
+            if let Err(err) = self
+                .send_list_mutation(mutation, &id_hash.0, &question_id)
+                .await
+            {
+                if applied.is_empty() {
+                    return Err(err);
+                }
+                let _ = self.refresh_lists().await;
+                return Err(Errors::PartialListUpdate {
+                    applied,
+                    failed: question_id,
+                    source: Box::new(err),
+                });
+            }
+            applied.push(question_id);
+        }
+
+        self.refresh_lists().await
     }
 
-    pub async fn delete_list(&self, list_name: &str) -> Result<&MyProfile, Errors> {
-        let id_hash = if let Some(id) = Self::get_id_hash(&self, list_name) {
-            id
-        } else {
-            return Err(Errors::ApiError(
-                "Provided name doesn't found in lists".into(),
-            ));
+    async fn send_list_mutation(
+        &self,
+        mutation: &str,
+        id_hash: &str,
+        question_id: &str,
+    ) -> Result<(), Errors> {
+        let query = format!(
+            "mutation {0}($favoriteIdHash: String!, $questionId: String!) {{
+                result: {0}(favoriteIdHash: $favoriteIdHash, questionId: $questionId) {{
+                    ok
+                    error
+                    favoriteIdHash
+                    questionId
+                }}
+            }}",
+            mutation
+        );
+
+        let json_data = json!({
+            "operationName": mutation,
+            "variables": {
+                "favoriteIdHash": id_hash,
+                "questionId": question_id,
+            },
+            "query": query,
+        });
+
+        let query = serde_json::to_string(&json_data)?;
+
+        let action_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        let result = serde_json::from_str::<FavoriteAction>(&action_info)?
+            .data
+            .result;
+        if !result.ok {
+            return Err(Errors::ApiError(result.error.unwrap_or(format!(
+                "Can not change question `{}` in list",
+                question_id
+            ))));
+        }
+        Ok(())
+    }
+
+    async fn question_id(&self, question: QuestionRef<'_>) -> Result<String, Errors> {
+        let title_slug = match question {
+            QuestionRef::Problem(problem) => {
+                return Ok(problem.full_data.data.question.questionId.clone())
+            }
+            QuestionRef::Slug(title_slug) => title_slug,
         };
 
-        self.client
-            .delete(format!("https://leetcode.com/list/api/{}", id_hash.0))
+        self.find_question_id(title_slug)
+            .await?
+            .ok_or_else(|| Errors::ApiError(format!("Problem `{}` not found", title_slug)))
+    }
+
+    async fn find_question_id(&self, title_slug: &str) -> Result<Option<String>, Errors> {
+        let query = json!({
+            "operationName": "questionId",
+            "variables": { "titleSlug": title_slug },
+            "query": "query questionId($titleSlug: String!) {
+                question(titleSlug: $titleSlug) {
+                    questionId
+                }
+            }"
+        });
+
+        let query = serde_json::to_string(&query)?;
+
+        let question_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
             .send()
+            .await?
+            .text()
             .await?;
 
-        Ok(self)
+        Ok(serde_json::from_str::<QuestionId>(&question_info)?
+            .data
+            .question
+            .map(|question| question.questionId))
+    }
+
+    fn list_description(&self, id_hash: &str) -> String {
+        self.fav_lists
+            .data
+            .favoritesLists
+            .allFavorites
+            .iter()
+            .find(|favourite| favourite.idHash == id_hash)
+            .map(|favourite| favourite.description.clone())
+            .unwrap_or_default()
     }
 
     fn get_id_hash(&self, list_name: &str) -> Option<(String, bool)> {
         for favourite in &self.fav_lists.data.favoritesLists.allFavorites {
-            if favourite.name == list_name.to_string() {
-                return Some((favourite.idHash.clone(), favourite.isPublicFavorite.clone()));
+            if favourite.name == list_name {
+                return Some((favourite.idHash.clone(), favourite.isPublicFavorite));
             }
         }
         None
     }
 
     pub async fn get_notifications(&self) -> Result<NotificationsData, Errors> {
+        self.fetch_notifications(10, None).await
+    }
+
+    pub fn notifications(&self) -> impl Stream<Item = Result<NotificationNode, Errors>> + '_ {
+        let state = NotificationState {
+            after: None,
+            has_next: true,
+            buffer: VecDeque::new(),
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(notification) = state.buffer.pop_front() {
+                    return Some((Ok(notification), state));
+                }
+                if !state.has_next {
+                    return None;
+                }
+
+                let page = match self
+                    .fetch_notifications(NOTIFICATION_PAGE_LIMIT, state.after.as_deref())
+                    .await
+                {
+                    Ok(page) => page.data.notifications,
+                    Err(err) => {
+                        state.has_next = false;
+                        return Some((Err(err), state));
+                    }
+                };
+
+                state.has_next = page.pageInfo.hasNextPage && page.pageInfo.endCursor.is_some();
+                state.after = page.pageInfo.endCursor;
+                state
+                    .buffer
+                    .extend(page.edges.into_iter().map(|edge| edge.node));
+            }
+        })
+    }
+
+    pub fn watch_notifications(
+        &self,
+        interval: Duration,
+        state_path: &str,
+    ) -> impl Stream<Item = Result<NotificationNode, Errors>> + '_ {
+        let state = WatcherState {
+            path: PathBuf::from(state_path),
+            watch: None,
+            status: None,
+            started: false,
+            buffer: VecDeque::new(),
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(notification) = state.buffer.pop_front() {
+                    if let Err(err) = state.mark_seen(&notification).await {
+                        state.buffer.push_front(notification);
+                        return Some((Err(err), state));
+                    }
+                    return Some((Ok(notification), state));
+                }
+                if let Err(err) = state.apply_status().await {
+                    return Some((Err(err), state));
+                }
+                if state.started {
+                    tokio::time::sleep(interval).await;
+                }
+                state.started = true;
+
+                if let Err(err) = self.poll_notifications(&mut state).await {
+                    return Some((Err(err), state));
+                }
+            }
+        })
+    }
+
+    async fn poll_notifications(&self, state: &mut WatcherState) -> Result<(), Errors> {
+        let (mut watch, is_new) = match state.watch.take() {
+            Some(watch) => (watch, false),
+            None if state.path.exists() => {
+                let data = tokio::fs::read_to_string(&state.path).await?;
+                (serde_json::from_str::<WatchState>(&data)?, false)
+            }
+            None => (WatchState::default(), true),
+        };
+
+        let status = self
+            .profile_info()
+            .await?
+            .data
+            .userStatus
+            .notificationStatus;
+        if !is_new
+            && status.lastModified == watch.last_modified
+            && status.numUnread == watch.num_unread
+        {
+            state.watch = Some(watch);
+            return Ok(());
+        }
+
+        let mut unseen = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let page = self
+                .fetch_notifications(NOTIFICATION_PAGE_LIMIT, after.as_deref())
+                .await?
+                .data
+                .notifications;
+            let mut reached_seen = false;
+            for edge in page.edges {
+                if watch.is_unseen(&edge.node) {
+                    unseen.push(edge.node);
+                } else if edge.node.modifiedDate < watch.last_seen_date {
+                    reached_seen = true;
+                }
+            }
+            if reached_seen || is_new || !page.pageInfo.hasNextPage {
+                break;
+            }
+            match page.pageInfo.endCursor {
+                Some(cursor) => after = Some(cursor),
+                None => break,
+            }
+        }
+
+        unseen.sort_by_key(|notification| notification.modifiedDate);
+        if is_new {
+            for notification in &unseen {
+                watch.mark_seen(notification);
+            }
+            watch.last_modified = status.lastModified;
+            watch.num_unread = status.numUnread;
+            state.watch = Some(watch);
+            return state.save().await;
+        }
+
+        state.watch = Some(watch);
+        state.status = Some((status.lastModified, status.numUnread));
+        state.buffer.extend(unseen);
+        Ok(())
+    }
+
+    pub async fn mark_actioned(&self, notification_id: i32) -> Result<(), Errors> {
+        self.send_notification_mutation(
+            "mutation markNotificationActioned($notificationId: Int!) {
+                result: markNotificationActioned(notificationId: $notificationId) {
+                    ok
+                    error
+                }
+            }",
+            "markNotificationActioned",
+            json!({ "notificationId": notification_id }),
+        )
+        .await
+    }
+
+    pub async fn mark_all_read(&self) -> Result<(), Errors> {
+        self.send_notification_mutation(
+            "mutation markAllNotificationsAsRead {
+                result: markAllNotificationsAsRead {
+                    ok
+                    error
+                }
+            }",
+            "markAllNotificationsAsRead",
+            json!({}),
+        )
+        .await
+    }
+
+    async fn send_notification_mutation(
+        &self,
+        query: &str,
+        operation_name: &str,
+        variables: serde_json::Value,
+    ) -> Result<(), Errors> {
+        let json_data = json!({
+            "operationName": operation_name,
+            "variables": variables,
+            "query": query,
+        });
+
+        let query = serde_json::to_string(&json_data)?;
+
+        let action_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        let result = serde_json::from_str::<NotificationAction>(&action_info)?
+            .data
+            .result;
+        if !result.ok {
+            return Err(Errors::ApiError(
+                result
+                    .error
+                    .unwrap_or(format!("Can not apply `{}`", operation_name)),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn fetch_notifications(
+        &self,
+        first: u32,
+        after: Option<&str>,
+    ) -> Result<NotificationsData, Errors> {
         let operation_name = "fetchNotifications";
-        let variables = json!({ "first": 10 });
+        let variables = json!({ "first": first, "after": after });
         let query = r#"query fetchNotifications($first: Int!, $after: String) {
         notifications(first: $first, after: $after) {
             edges {
@@ -195,6 +808,75 @@ impl MyProfile {
         Ok(serde_json::from_str::<NotificationsData>(&problem_info)?)
     }
 
+    pub fn all_submissions(
+        &self,
+        filter: SubmissionFilter,
+    ) -> impl Stream<Item = Result<SubmissionDump, Errors>> + '_ {
+        let state = DumpState {
+            offset: 0,
+            last_key: String::new(),
+            has_next: true,
+            buffer: VecDeque::new(),
+        };
+
+        stream::unfold(state, move |mut state| {
+            let filter = filter.clone();
+            async move {
+                loop {
+                    if let Some(subm) = state.buffer.pop_front() {
+                        if filter.is_older_than_range(subm.timestamp) {
+                            return None;
+                        }
+                        if filter.matches(&subm.status_display, &subm.lang, subm.timestamp) {
+                            return Some((Ok(subm), state));
+                        }
+                        continue;
+                    }
+                    if !state.has_next {
+                        return None;
+                    }
+
+                    let page = match self
+                        .fetch_submissions_dump(state.offset, &state.last_key)
+                        .await
+                    {
+                        Ok(page) => page,
+                        Err(err) => {
+                            state.has_next = false;
+                            return Some((Err(err), state));
+                        }
+                    };
+
+                    state.offset += DUMP_PAGE_LIMIT;
+                    state.has_next = page.has_next;
+                    state.last_key = page.last_key.unwrap_or_default();
+                    state.buffer.extend(page.submissions_dump);
+                }
+            }
+        })
+    }
+
+    async fn fetch_submissions_dump(
+        &self,
+        offset: u32,
+        last_key: &str,
+    ) -> Result<SubmissionsDumpPage, Errors> {
+        let page = self
+            .client
+            .get("https://leetcode.com/api/submissions/")
+            .query(&[
+                ("offset", offset.to_string()),
+                ("limit", DUMP_PAGE_LIMIT.to_string()),
+                ("lastkey", String::from(last_key)),
+            ])
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str::<SubmissionsDumpPage>(&page)?)
+    }
+
     pub async fn profile_info(&self) -> Result<ProfileData, Errors> {
         let query = r#"
         query globalData {
@@ -244,6 +926,141 @@ impl MyProfile {
     }
 }
 
+pub(crate) async fn fetch_fav_lists(client: &reqwest::Client) -> Result<FavoriteList, Errors> {
+    let query = json!({
+        "operationName": "favoritesList",
+        "variables": {},
+        "query": format!(
+            "query favoritesList {{
+                favoritesLists {{
+                    allFavorites {{ {0} }}
+                    watchedFavorites {{ {0} }}
+                    __typename
+                }}
+                userStatus {{
+                    username
+                    __typename
+                }}
+            }}",
+            FAVORITE_NODE_FIELDS
+        )
+    });
+
+    let query = serde_json::to_string(&query)?;
+
+    let list_data = client
+        .post("https://leetcode.com/graphql/")
+        .body(query)
+        .send()
+        .await?
+        .text()
+        .await?;
+
+    Ok(serde_json::from_str::<FavoriteList>(&list_data)?)
+}
+
+const FAVORITE_NODE_FIELDS: &str = "
+    idHash
+    name
+    description
+    viewCount
+    creator
+    isWatched
+    isPublicFavorite
+    questions {
+        questionId
+        status
+        title
+        titleSlug
+        __typename
+    }
+    __typename
+";
+
+pub(crate) async fn fetch_favorite_list(
+    client: &reqwest::Client,
+    id_hash: &str,
+) -> Result<FavoriteNode, Errors> {
+    let query = json!({
+        "operationName": "favoriteDetail",
+        "variables": { "favoriteIdHash": id_hash },
+        "query": format!(
+            "query favoriteDetail($favoriteIdHash: String!) {{
+                favoriteDetail(favoriteIdHash: $favoriteIdHash) {{ {} }}
+            }}",
+            FAVORITE_NODE_FIELDS
+        )
+    });
+
+    let query = serde_json::to_string(&query)?;
+
+    let list_data = client
+        .post("https://leetcode.com/graphql/")
+        .body(query)
+        .send()
+        .await?
+        .text()
+        .await?;
+
+    serde_json::from_str::<FavoriteDetail>(&list_data)?
+        .data
+        .favoriteDetail
+        .ok_or_else(|| Errors::ApiError(format!("List `{}` not found", id_hash)))
+}
+
+const DUMP_PAGE_LIMIT: u32 = 20;
+
+const NOTIFICATION_PAGE_LIMIT: u32 = 20;
+
+const PUBLIC_LIST_PAGE_LIMIT: u32 = 20;
+
+struct WatcherState {
+    path: PathBuf,
+    watch: Option<WatchState>,
+    status: Option<(i64, i32)>,
+    started: bool,
+    buffer: VecDeque<NotificationNode>,
+}
+
+impl WatcherState {
+    async fn mark_seen(&mut self, notification: &NotificationNode) -> Result<(), Errors> {
+        self.watch
+            .get_or_insert_with(WatchState::default)
+            .mark_seen(notification);
+        self.save().await
+    }
+
+    async fn apply_status(&mut self) -> Result<(), Errors> {
+        let Some((last_modified, num_unread)) = self.status.take() else {
+            return Ok(());
+        };
+        let watch = self.watch.get_or_insert_with(WatchState::default);
+        watch.last_modified = last_modified;
+        watch.num_unread = num_unread;
+        self.save().await
+    }
+
+    async fn save(&self) -> Result<(), Errors> {
+        if let Some(watch) = &self.watch {
+            tokio::fs::write(&self.path, serde_json::to_string_pretty(watch)?).await?;
+        }
+        Ok(())
+    }
+}
+
+struct NotificationState {
+    after: Option<String>,
+    has_next: bool,
+    buffer: VecDeque<NotificationNode>,
+}
+
+struct DumpState {
+    offset: u32,
+    last_key: String,
+    has_next: bool,
+    buffer: VecDeque<SubmissionDump>,
+}
+
 #[derive(Debug)]
 pub struct UserProfile {
     pub(crate) client: reqwest::Client,
@@ -251,6 +1068,113 @@ pub struct UserProfile {
 }
 
 impl UserProfile {
+    pub async fn contest_ranking(&self) -> Result<Option<UserContestRanking>, Errors> {
+        Ok(self.fetch_contest_data().await?.userContestRanking)
+    }
+
+    pub async fn contest_history(&self) -> Result<Vec<ContestResult>, Errors> {
+        Ok(contest::contest_results(
+            self.fetch_contest_data()
+                .await?
+                .userContestRankingHistory
+                .unwrap_or_default(),
+        ))
+    }
+
+    async fn fetch_contest_data(&self) -> Result<UserContestData, Errors> {
+        let query = json!({
+            "operationName": "userContestRankingInfo",
+            "variables": { "username": self.username },
+            "query": "query userContestRankingInfo($username: String!) {
+                userContestRanking(username: $username) {
+                    attendedContestsCount
+                    rating
+                    globalRanking
+                    totalParticipants
+                    topPercentage
+                    badge {
+                        name
+                    }
+                }
+                userContestRankingHistory(username: $username) {
+                    attended
+                    trendDirection
+                    problemsSolved
+                    totalProblems
+                    finishTimeInSeconds
+                    rating
+                    ranking
+                    contest {
+                        title
+                        startTime
+                    }
+                }
+            }"
+        });
+
+        let query = serde_json::to_string(&query)?;
+
+        let contest_info = self
+            .client
+            .post("https://leetcode.com/graphql/")
+            .body(query)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        Ok(serde_json::from_str::<UserContest>(&contest_info)?.data)
+    }
+
+    pub async fn public_lists(&self) -> Result<Vec<FavoriteNode>, Errors> {
+        let mut lists = Vec::new();
+
+        loop {
+            let query = json!({
+                "operationName": "createdPublicFavoriteList",
+                "variables": {
+                    "userSlug": self.username,
+                    "skip": lists.len(),
+                    "limit": PUBLIC_LIST_PAGE_LIMIT
+                },
+                "query": format!(
+                    "query createdPublicFavoriteList($userSlug: String!, $skip: Int, $limit: Int) {{
+                        createdPublicFavoriteList(userSlug: $userSlug, skip: $skip, limit: $limit) {{
+                            hasMore
+                            totalLength
+                            favorites {{ {} }}
+                        }}
+                    }}",
+                    FAVORITE_NODE_FIELDS
+                )
+            });
+
+            let query = serde_json::to_string(&query)?;
+
+            let list_data = self
+                .client
+                .post("https://leetcode.com/graphql/")
+                .body(query)
+                .send()
+                .await?
+                .text()
+                .await?;
+
+            let Some(page) = serde_json::from_str::<PublicFavorites>(&list_data)?
+                .data
+                .createdPublicFavoriteList
+            else {
+                return Ok(lists);
+            };
+
+            let received = page.favorites.len();
+            lists.extend(page.favorites);
+            if !page.hasMore || received == 0 || lists.len() >= page.totalLength as usize {
+                return Ok(lists);
+            }
+        }
+    }
+
     pub async fn user_stats(&self) -> Result<UserFoundData, Errors> {
         let query = json!({
             "query": "query userPublicProfile($username: String!) {\n  matchedUser(username: $username) {\n    contestBadge {\n      name\n      expired\n      hoverText\n      icon\n    }\n    username\n    githubUrl\n    twitterUrl\n    linkedinUrl\n    profile {\n      ranking\n      userAvatar\n      realName\n      aboutMe\n      school\n      websites\n      countryName\n      company\n      jobTitle\n      skillTags\n      postViewCount\n      postViewCountDiff\n      reputation\n      reputationDiff\n      solutionCount\n      solutionCountDiff\n      categoryDiscussCount\n      categoryDiscussCountDiff\n    }\n  }\n}",
@@ -0,0 +1,850 @@
+use crate::resources::{Description, DescriptionSections, Example};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Token {
+    Open(String, Vec<(String, String)>),
+    Close(String),
+    Text(String),
+}
+
+pub(crate) fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = match comment.find("-->") {
+                Some(end) => &comment[end + 3..],
+                None => "",
+            };
+            continue;
+        }
+
+        let is_tag = rest.starts_with('<')
+            && rest[1..]
+                .chars()
+                .next()
+                .is_some_and(|ch| ch.is_ascii_alphabetic() || ch == '/');
+
+        if is_tag {
+            let Some(end) = rest.find('>') else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim().to_lowercase()));
+                continue;
+            }
+
+            let self_closing = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name_end = tag.find(|ch: char| ch.is_whitespace()).unwrap_or(tag.len());
+            let name = tag[..name_end].to_lowercase();
+            let attrs = parse_attrs(&tag[name_end..]);
+
+            tokens.push(Token::Open(name.clone(), attrs));
+            if self_closing || is_void(&name) {
+                tokens.push(Token::Close(name));
+            }
+            continue;
+        }
+
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, ch)| *ch == '<')
+            .map_or(rest.len(), |(pos, _)| pos);
+        tokens.push(Token::Text(decode_entities(&rest[..end])));
+        rest = &rest[end..];
+    }
+
+    tokens
+}
+
+fn is_void(name: &str) -> bool {
+    matches!(name, "br" | "img" | "hr" | "input" | "meta" | "link")
+}
+
+fn parse_attrs(attrs: &str) -> Vec<(String, String)> {
+    let mut parsed = Vec::new();
+    let mut rest = attrs.trim_start();
+
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|ch: char| ch == '=' || ch.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start();
+            let quote = value.chars().next().filter(|ch| *ch == '"' || *ch == '\'');
+            match quote {
+                Some(quote) => {
+                    let end = value[1..].find(quote).map_or(value.len(), |pos| pos + 1);
+                    rest = value.get(end + 1..).unwrap_or("");
+                    &value[1..end]
+                }
+                None => {
+                    let end = value
+                        .find(|ch: char| ch.is_whitespace())
+                        .unwrap_or(value.len());
+                    rest = &value[end..];
+                    &value[..end]
+                }
+            }
+        } else {
+            ""
+        };
+
+        if !key.is_empty() {
+            parsed.push((key, decode_entities(value)));
+        }
+        rest = rest.trim_start();
+    }
+
+    parsed
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+
+        let ch = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "le" => Some('≤'),
+            "ge" => Some('≥'),
+            "ne" => Some('≠'),
+            "times" => Some('×'),
+            "minus" => Some('−'),
+            "rarr" => Some('→'),
+            "larr" => Some('←'),
+            "hellip" => Some('…'),
+            "ndash" => Some('–'),
+            "mdash" => Some('—'),
+            "lsquo" => Some('‘'),
+            "rsquo" => Some('’'),
+            "ldquo" => Some('“'),
+            "rdquo" => Some('”'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| {
+                    entity
+                        .strip_prefix('#')
+                        .and_then(|dec| dec.parse::<u32>().ok())
+                })
+                .and_then(char::from_u32),
+        });
+
+        match (entity, ch) {
+            (Some(entity), Some(ch)) => {
+                decoded.push(ch);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Flavor {
+    Markdown,
+    Plain,
+}
+
+#[derive(Debug)]
+enum Block {
+    Text(String),
+    Pre(String),
+}
+
+struct Renderer {
+    flavor: Flavor,
+    blocks: Vec<Block>,
+    text: String,
+    pre: Option<String>,
+    lists: Vec<Option<u32>>,
+    links: Vec<Option<String>>,
+    inline: Vec<&'static str>,
+}
+
+impl Renderer {
+    fn new(flavor: Flavor) -> Self {
+        Self {
+            flavor,
+            blocks: Vec::new(),
+            text: String::new(),
+            pre: None,
+            lists: Vec::new(),
+            links: Vec::new(),
+            inline: Vec::new(),
+        }
+    }
+
+    fn render(mut self, html: &str) -> Vec<Block> {
+        for token in tokenize(html) {
+            match token {
+                Token::Open(name, attrs) => self.open(&name, &attrs),
+                Token::Close(name) => self.close(&name),
+                Token::Text(text) => self.push_text(&text),
+            }
+        }
+        self.flush_text();
+        if let Some(pre) = self.pre.take() {
+            self.blocks.push(Block::Pre(pre));
+        }
+        self.blocks
+    }
+
+    fn markdown(&self) -> bool {
+        self.flavor == Flavor::Markdown
+    }
+
+    fn open(&mut self, name: &str, attrs: &[(String, String)]) {
+        if self.pre.is_some() {
+            return;
+        }
+        match name {
+            "pre" => {
+                self.flush_text();
+                self.pre = Some(String::new());
+            }
+            "p" | "div" => self.break_paragraph(),
+            "br" => self.text.push('\n'),
+            "ul" => {
+                self.break_line();
+                self.lists.push(None);
+            }
+            "ol" => {
+                self.break_line();
+                self.lists.push(Some(0));
+            }
+            "li" => {
+                self.break_line();
+                let depth = self.lists.len().saturating_sub(1);
+                self.text.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(Some(counter)) => {
+                        *counter += 1;
+                        self.text.push_str(&format!("{}. ", counter));
+                    }
+                    _ => self.text.push_str("- "),
+                }
+            }
+            "strong" | "b" if self.markdown() => self.open_inline("**"),
+            "em" | "i" if self.markdown() => self.open_inline("*"),
+            "code" if self.markdown() => self.open_inline("`"),
+            "sup" => self.text.push('^'),
+            "sub" => self.text.push('_'),
+            "a" => {
+                let href = attr(attrs, "href").map(String::from);
+                if self.markdown() && href.is_some() {
+                    self.text.push('[');
+                }
+                self.links.push(href);
+            }
+            "img" => {
+                let src = attr(attrs, "src").unwrap_or_default();
+                let alt = attr(attrs, "alt").unwrap_or_default();
+                self.break_line();
+                if self.markdown() {
+                    self.text.push_str(&format!("![{}]({})", alt, src));
+                } else {
+                    self.text.push_str(&format!("[image: {}]", src));
+                }
+                self.text.push('\n');
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.break_paragraph();
+                if self.markdown() {
+                    let level = name[1..].parse::<usize>().unwrap_or(1);
+                    self.text.push_str(&"#".repeat(level));
+                    self.text.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if name == "pre" {
+            if let Some(pre) = self.pre.take() {
+                self.blocks.push(Block::Pre(pre));
+            }
+            return;
+        }
+        if self.pre.is_some() {
+            return;
+        }
+        match name {
+            "p" | "div" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.break_paragraph(),
+            "ul" | "ol" => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.break_paragraph();
+                }
+            }
+            "li" => self.break_line(),
+            "strong" | "b" if self.markdown() => self.close_inline("**"),
+            "em" | "i" if self.markdown() => self.close_inline("*"),
+            "code" if self.markdown() => self.close_inline("`"),
+            "a" => {
+                if let Some(Some(href)) = self.links.pop() {
+                    if self.markdown() {
+                        self.text.push_str(&format!("]({})", href));
+                    } else {
+                        self.text.push_str(&format!(" ({})", href));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn open_inline(&mut self, marker: &'static str) {
+        self.text.push_str(marker);
+        self.inline.push(marker);
+    }
+
+    fn close_inline(&mut self, marker: &str) {
+        let Some(pos) = self.inline.iter().rposition(|open| *open == marker) else {
+            return;
+        };
+        self.inline.remove(pos);
+        self.push_marker(marker);
+    }
+
+    fn push_marker(&mut self, marker: &str) {
+        let trimmed = self.text.trim_end_matches(' ').len();
+        let trailing = self.text.len() - trimmed;
+        self.text.truncate(trimmed);
+        self.text.push_str(marker);
+        self.text.push_str(&" ".repeat(trailing));
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(pre) = self.pre.as_mut() {
+            pre.push_str(text);
+            return;
+        }
+
+        let mut last_space =
+            self.text.is_empty() || self.text.ends_with(' ') || self.text.ends_with('\n');
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                if !last_space {
+                    self.text.push(' ');
+                    last_space = true;
+                }
+            } else {
+                self.text.push(ch);
+                last_space = false;
+            }
+        }
+    }
+
+    fn break_line(&mut self) {
+        let trimmed = self.text.trim_end_matches(' ').len();
+        self.text.truncate(trimmed);
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+    }
+
+    fn break_paragraph(&mut self) {
+        self.break_line();
+        if !self.text.is_empty() && !self.text.ends_with("\n\n") {
+            self.text.push('\n');
+        }
+    }
+
+    fn flush_text(&mut self) {
+        while let Some(marker) = self.inline.pop() {
+            self.push_marker(marker);
+        }
+        let text = std::mem::take(&mut self.text);
+        let text = text
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<&str>>()
+            .join("\n");
+        if !text.trim().is_empty() {
+            self.blocks
+                .push(Block::Text(text.trim_matches('\n').to_owned()));
+        }
+    }
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.as_str())
+}
+
+pub(crate) fn html_to_markdown(html: &str) -> String {
+    let blocks = Renderer::new(Flavor::Markdown).render(html);
+
+    let rendered = blocks
+        .iter()
+        .map(|block| match block {
+            Block::Text(text) => collapse_blank_lines(text),
+            Block::Pre(pre) => format!("```\n{}\n```", pre.trim_matches('\n')),
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    rendered.trim().to_owned()
+}
+
+pub(crate) fn html_to_plain_text(html: &str, width: usize) -> String {
+    let blocks = Renderer::new(Flavor::Plain).render(html);
+
+    let rendered = blocks
+        .iter()
+        .map(|block| match block {
+            Block::Text(text) => wrap(&collapse_blank_lines(text), width),
+            Block::Pre(pre) => pre.trim_matches('\n').to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    rendered.trim().to_owned()
+}
+
+fn collapse_blank_lines(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut blank = 0;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank += 1;
+            if blank > 1 {
+                continue;
+            }
+        } else {
+            blank = 0;
+        }
+        collapsed.push_str(line);
+        collapsed.push('\n');
+    }
+    collapsed.trim_end().to_owned()
+}
+
+fn wrap(text: &str, width: usize) -> String {
+    if width == 0 {
+        return text.to_owned();
+    }
+
+    let mut wrapped = Vec::new();
+    for line in text.lines() {
+        let indent = hanging_indent(line);
+        let mut current = String::new();
+        for word in line.split(' ').filter(|word| !word.is_empty()) {
+            let len = current.chars().count();
+            if current.is_empty() {
+                current.push_str(&line[..line.len() - line.trim_start().len()]);
+                current.push_str(word);
+            } else if len + 1 + word.chars().count() > width {
+                wrapped.push(std::mem::take(&mut current));
+                current.push_str(&" ".repeat(indent));
+                current.push_str(word);
+            } else {
+                current.push(' ');
+                current.push_str(word);
+            }
+        }
+        wrapped.push(current);
+    }
+
+    wrapped.join("\n")
+}
+
+fn hanging_indent(line: &str) -> usize {
+    let leading = line.len() - line.trim_start().len();
+    let rest = line.trim_start();
+    if rest.starts_with("- ") {
+        return leading + 2;
+    }
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 && rest[digits..].starts_with(". ") {
+        return leading + digits + 2;
+    }
+    leading
+}
+
+impl Description {
+    pub fn to_markdown(&self) -> String {
+        format!("# {}\n\n{}\n", self.name, html_to_markdown(&self.content))
+    }
+
+    pub fn to_plain_text(&self, width: usize) -> String {
+        format!(
+            "{}\n\n{}\n",
+            self.name,
+            html_to_plain_text(&self.content, width)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Section {
+    Statement,
+    Example,
+    Constraints,
+    FollowUp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExampleField {
+    None,
+    Input,
+    Output,
+    Explanation,
+}
+
+pub(crate) fn split_sections(html: &str) -> DescriptionSections {
+    let text = html_to_plain_text(html, 0);
+
+    let mut sections = DescriptionSections::default();
+    let mut statement = Vec::new();
+    let mut follow_up = Vec::new();
+    let mut section = Section::Statement;
+    let mut field = ExampleField::None;
+
+    for line in text.lines() {
+        let line = line.trim();
+
+        if is_example_header(line) {
+            sections.examples.push(Example::default());
+            section = Section::Example;
+            field = ExampleField::None;
+            continue;
+        }
+        if line.eq_ignore_ascii_case("constraints:") {
+            section = Section::Constraints;
+            continue;
+        }
+        if let Some(rest) = strip_follow_up(line) {
+            section = Section::FollowUp;
+            if !rest.is_empty() {
+                follow_up.push(rest);
+            }
+            continue;
+        }
+
+        match section {
+            Section::Statement => statement.push(line),
+            Section::FollowUp => {
+                if !line.is_empty() {
+                    follow_up.push(line);
+                }
+            }
+            Section::Constraints => {
+                let constraint = line.strip_prefix("- ").unwrap_or(line).trim();
+                if !constraint.is_empty() {
+                    sections.constraints.push(String::from(constraint));
+                }
+            }
+            Section::Example => {
+                let Some(example) = sections.examples.last_mut() else {
+                    continue;
+                };
+                if let Some(image) = line
+                    .strip_prefix("[image: ")
+                    .and_then(|image| image.strip_suffix(']'))
+                {
+                    example.image = Some(String::from(image));
+                } else if let Some(input) = line.strip_prefix("Input:") {
+                    field = ExampleField::Input;
+                    example.input = String::from(input.trim());
+                } else if let Some(output) = line.strip_prefix("Output:") {
+                    field = ExampleField::Output;
+                    example.output = String::from(output.trim());
+                } else if let Some(explanation) = line.strip_prefix("Explanation:") {
+                    field = ExampleField::Explanation;
+                    example.explanation = Some(String::from(explanation.trim()));
+                } else if !line.is_empty() {
+                    let target = match field {
+                        ExampleField::Input => Some(&mut example.input),
+                        ExampleField::Output => Some(&mut example.output),
+                        ExampleField::Explanation => example.explanation.as_mut(),
+                        ExampleField::None => None,
+                    };
+                    if let Some(target) = target {
+                        if !target.is_empty() {
+                            target.push('\n');
+                        }
+                        target.push_str(line);
+                    }
+                }
+            }
+        }
+    }
+
+    sections.statement = collapse_blank_lines(&statement.join("\n"))
+        .trim()
+        .to_owned();
+    if !follow_up.is_empty() {
+        sections.follow_up = Some(follow_up.join("\n"));
+    }
+
+    sections
+}
+
+fn is_example_header(line: &str) -> bool {
+    line.strip_prefix("Example")
+        .and_then(|rest| rest.strip_suffix(':'))
+        .is_some_and(|number| number.trim().chars().all(|ch| ch.is_ascii_digit()))
+}
+
+fn strip_follow_up(line: &str) -> Option<&str> {
+    let lower = line.to_lowercase();
+    ["follow up", "follow-up", "followup"]
+        .iter()
+        .find(|prefix| lower.starts_with(*prefix))
+        .map(|prefix| {
+            line[prefix.len()..].trim_start_matches(|ch: char| ch == ':' || ch.is_whitespace())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_sections, tokenize, Token};
+    use crate::resources::Description;
+
+    const PRE_EXAMPLES: &str = "<p>Given an array of integers <code>nums</code>&nbsp;and an integer <code>target</code>.</p>\n\n<p>&nbsp;</p>\n<p><strong class=\"example\">Example 1:</strong></p>\n\n<pre>\n<strong>Input:</strong> nums = [2,7,11,15], target = 9\n<strong>Output:</strong> [0,1]\n<strong>Explanation:</strong> Because nums[0] + nums[1] == 9, we return [0, 1].\n</pre>\n\n<p><strong class=\"example\">Example 2:</strong></p>\n\n<pre>\n<strong>Input:</strong> nums = [3,2,4], target = 6\n<strong>Output:</strong> [1,2]\n</pre>\n\n<p>&nbsp;</p>\n<p><strong>Constraints:</strong></p>\n\n<ul>\n\t<li><code>2 &lt;= nums.length &lt;= 10<sup>4</sup></code></li>\n\t<li><strong>Only one valid answer exists.</strong></li>\n</ul>\n\n<p>&nbsp;</p>\n<strong>Follow-up:&nbsp;</strong>Can you come up with an algorithm that is less than <code>O(n<sup>2</sup>)</code><font face=\"monospace\">&nbsp;</font>time complexity?";
+
+    const EXAMPLE_BLOCKS: &str = "<p>You are given an integer array <code>nums</code>.</p>\n\n<p>&nbsp;</p>\n<p><strong class=\"example\">Example 1:</strong></p>\n\n<div class=\"example-block\">\n<p><strong>Input:</strong> <span class=\"example-io\">nums = [1,2,3,4]</span></p>\n\n<p><strong>Output:</strong> <span class=\"example-io\">[1,3]</span></p>\n\n<p><strong>Explanation:</strong></p>\n\n<ul>\n\t<li>At index 0, pick 1.</li>\n\t<li>At index 2, pick 3.</li>\n</ul>\n</div>\n\n<p><strong class=\"example\">Example 2:</strong></p>\n\n<div class=\"example-block\">\n<p><strong>Input:</strong> <span class=\"example-io\">nums = [5]</span></p>\n\n<p><strong>Output:</strong> <span class=\"example-io\">[5]</span></p>\n</div>\n\n<p>&nbsp;</p>\n<p><strong>Constraints:</strong></p>\n\n<ul>\n\t<li><code>1 &lt;= nums.length &lt;= 100</code></li>\n</ul>\n\n<p>&nbsp;</p>\n<p><strong>Follow up:</strong> Can you solve it in <code>O(n)</code>?</p>\n";
+
+    const IMAGE_EXAMPLES: &str = "<p>Given the <code>root</code> of a binary tree, return the maximum path sum.</p>\n\n<p>&nbsp;</p>\n<p><strong class=\"example\">Example 1:</strong></p>\n<img alt=\"\" src=\"https://assets.leetcode.com/uploads/2020/10/13/exx1.jpg\" style=\"width: 322px; height: 182px;\" />\n<pre>\n<strong>Input:</strong> root = [1,2,3]\n<strong>Output:</strong> 6\n<strong>Explanation:</strong> The optimal path is 2 -&gt; 1 -&gt; 3\nwith a path sum of 2 + 1 + 3 = 6.\n</pre>\n\n<p><strong class=\"example\">Example 2:</strong></p>\n\n<div class=\"example-block\">\n<p><img alt=\"\" src=\"https://assets.leetcode.com/uploads/2020/10/13/exx2.jpg\" /></p>\n\n<p><strong>Input:</strong> <span class=\"example-io\">root = [-3]</span></p>\n\n<p><strong>Output:</strong> <span class=\"example-io\">-3</span></p>\n</div>\n\n<p>&nbsp;</p>\n<p><strong>Constraints:</strong></p>\n\n<ul>\n\t<li>The number of nodes in the tree is in the range <code>[1, 3 * 10<sup>4</sup>]</code>.</li>\n</ul>\n";
+
+    fn description(content: &str) -> Description {
+        Description {
+            name: String::from("Two Sum"),
+            content: String::from(content),
+        }
+    }
+
+    #[test]
+    fn tokenize_decodes_entities_in_text_and_attributes() {
+        assert_eq!(
+            tokenize(r#"<p class="a&amp;b">x &lt; y&nbsp;&#x2264; &#8805; &foo; a&b</p>"#),
+            vec![
+                Token::Open(
+                    String::from("p"),
+                    vec![(String::from("class"), String::from("a&b"))]
+                ),
+                Token::Text(String::from("x < y ≤ ≥ &foo; a&b")),
+                Token::Close(String::from("p")),
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_closes_void_tags_and_keeps_unfinished_tags_as_text() {
+        assert_eq!(
+            tokenize(r#"<img src="a.png"><br/>a < b <a href="x""#),
+            vec![
+                Token::Open(
+                    String::from("img"),
+                    vec![(String::from("src"), String::from("a.png"))]
+                ),
+                Token::Close(String::from("img")),
+                Token::Open(String::from("br"), Vec::new()),
+                Token::Close(String::from("br")),
+                Token::Text(String::from("a ")),
+                Token::Text(String::from("< b ")),
+                Token::Text(String::from(r#"<a href="x""#)),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_entities_and_superscripts() {
+        let description = description(
+            "<p>Return <code>nums[i] &lt; target</code> where <code>-10<sup>9</sup> &lt;= x</code>.</p>",
+        );
+
+        assert_eq!(
+            description.to_markdown(),
+            "# Two Sum\n\nReturn `nums[i] < target` where `-10^9 <= x`.\n"
+        );
+        assert_eq!(
+            description.to_plain_text(0),
+            "Two Sum\n\nReturn nums[i] < target where -10^9 <= x.\n"
+        );
+    }
+
+    #[test]
+    fn renders_pre_examples_verbatim() {
+        let description = description(
+            "<p><strong class=\"example\">Example 1:</strong></p>\n<pre>\n<strong>Input:</strong> nums = [2,7,11,15], target = 9\n<strong>Output:</strong> [0,1]\n</pre>",
+        );
+
+        assert_eq!(
+            description.to_markdown(),
+            "# Two Sum\n\n**Example 1:**\n\n```\nInput: nums = [2,7,11,15], target = 9\nOutput: [0,1]\n```\n"
+        );
+        assert_eq!(
+            description.to_plain_text(10),
+            "Two Sum\n\nExample 1:\n\nInput: nums = [2,7,11,15], target = 9\nOutput: [0,1]\n"
+        );
+    }
+
+    #[test]
+    fn renders_nested_lists() {
+        let description = description(
+            "<ul><li>Each input has <strong>one</strong> solution<ol><li>first</li><li>second</li></ol></li><li>last</li></ul>",
+        );
+
+        assert_eq!(
+            description.to_markdown(),
+            "# Two Sum\n\n- Each input has **one** solution\n  1. first\n  2. second\n- last\n"
+        );
+        assert_eq!(
+            description.to_plain_text(16),
+            "Two Sum\n\n- Each input has\n  one solution\n  1. first\n  2. second\n- last\n"
+        );
+    }
+
+    #[test]
+    fn renders_images() {
+        let description = description(
+            r#"<p><img alt="tree" src="https://assets.leetcode.com/a.jpg?v=1&amp;w=2" style="width: 300px;" /></p>"#,
+        );
+
+        assert_eq!(
+            description.to_markdown(),
+            "# Two Sum\n\n![tree](https://assets.leetcode.com/a.jpg?v=1&w=2)\n"
+        );
+        assert_eq!(
+            description.to_plain_text(0),
+            "Two Sum\n\n[image: https://assets.leetcode.com/a.jpg?v=1&w=2]\n"
+        );
+    }
+
+    #[test]
+    fn closes_unclosed_tags() {
+        let description = description("<p>Unclosed <strong>bold <em>text");
+
+        assert_eq!(
+            description.to_markdown(),
+            "# Two Sum\n\nUnclosed **bold *text***\n"
+        );
+        assert_eq!(
+            description.to_plain_text(0),
+            "Two Sum\n\nUnclosed bold text\n"
+        );
+    }
+
+    #[test]
+    fn splits_pre_examples() {
+        let sections = split_sections(PRE_EXAMPLES);
+
+        assert_eq!(
+            sections.statement,
+            "Given an array of integers nums and an integer target."
+        );
+        assert_eq!(sections.examples.len(), 2);
+        assert_eq!(sections.examples[0].input, "nums = [2,7,11,15], target = 9");
+        assert_eq!(sections.examples[0].output, "[0,1]");
+        assert_eq!(
+            sections.examples[0].explanation.as_deref(),
+            Some("Because nums[0] + nums[1] == 9, we return [0, 1].")
+        );
+        assert_eq!(sections.examples[1].input, "nums = [3,2,4], target = 6");
+        assert_eq!(sections.examples[1].output, "[1,2]");
+        assert_eq!(sections.examples[1].explanation, None);
+        assert_eq!(
+            sections.constraints,
+            vec!["2 <= nums.length <= 10^4", "Only one valid answer exists."]
+        );
+        assert_eq!(
+            sections.follow_up.as_deref(),
+            Some("Can you come up with an algorithm that is less than O(n^2) time complexity?")
+        );
+    }
+
+    #[test]
+    fn splits_example_blocks() {
+        let sections = split_sections(EXAMPLE_BLOCKS);
+
+        assert_eq!(sections.statement, "You are given an integer array nums.");
+        assert_eq!(sections.examples.len(), 2);
+        assert_eq!(sections.examples[0].input, "nums = [1,2,3,4]");
+        assert_eq!(sections.examples[0].output, "[1,3]");
+        assert_eq!(
+            sections.examples[0].explanation.as_deref(),
+            Some("- At index 0, pick 1.\n- At index 2, pick 3.")
+        );
+        assert_eq!(sections.examples[1].input, "nums = [5]");
+        assert_eq!(sections.examples[1].output, "[5]");
+        assert_eq!(sections.constraints, vec!["1 <= nums.length <= 100"]);
+        assert_eq!(
+            sections.follow_up.as_deref(),
+            Some("Can you solve it in O(n)?")
+        );
+    }
+
+    #[test]
+    fn splits_examples_with_images() {
+        let sections = split_sections(IMAGE_EXAMPLES);
+
+        assert_eq!(sections.examples.len(), 2);
+        assert_eq!(
+            sections.examples[0].image.as_deref(),
+            Some("https://assets.leetcode.com/uploads/2020/10/13/exx1.jpg")
+        );
+        assert_eq!(sections.examples[0].input, "root = [1,2,3]");
+        assert_eq!(sections.examples[0].output, "6");
+        assert_eq!(
+            sections.examples[0].explanation.as_deref(),
+            Some("The optimal path is 2 -> 1 -> 3\nwith a path sum of 2 + 1 + 3 = 6.")
+        );
+        assert_eq!(
+            sections.examples[1].image.as_deref(),
+            Some("https://assets.leetcode.com/uploads/2020/10/13/exx2.jpg")
+        );
+        assert_eq!(sections.examples[1].input, "root = [-3]");
+        assert_eq!(sections.examples[1].output, "-3");
+        assert_eq!(sections.follow_up, None);
+    }
+
+    #[test]
+    fn splits_follow_up_variants() {
+        for html in [
+            "<p>Statement.</p><p><strong>Follow up:</strong> Solve it in place.</p>",
+            "<p>Statement.</p><p><strong>Follow-up:</strong>&nbsp;Solve it in place.</p>",
+            "<p>Statement.</p><p><strong>Follow up</strong></p><p>Solve it in place.</p>",
+        ] {
+            let sections = split_sections(html);
+            assert_eq!(sections.statement, "Statement.");
+            assert_eq!(sections.follow_up.as_deref(), Some("Solve it in place."));
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::subm_send::SubmissionId;
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UpcomingContest {
+    pub title: String,
+    pub titleSlug: String,
+    pub startTime: i64,
+    pub duration: i64,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct UpcomingContestsData {
+    pub upcomingContests: Vec<UpcomingContest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpcomingContests {
+    pub data: UpcomingContestsData,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PastContest {
+    pub title: String,
+    pub titleSlug: String,
+    pub startTime: i64,
+    pub originStartTime: Option<i64>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PastContestsPage {
+    pub pageNum: u32,
+    pub currentPage: u32,
+    pub totalNum: u32,
+    pub numPerPage: u32,
+    pub data: Vec<PastContest>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct PastContestsData {
+    pub pastContests: PastContestsPage,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PastContests {
+    pub data: PastContestsData,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContestMeta {
+    pub id: u32,
+    pub title: String,
+    pub title_slug: String,
+    pub start_time: i64,
+    pub duration: i64,
+    pub is_virtual: Option<bool>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContestQuestion {
+    pub id: u32,
+    pub question_id: u32,
+    pub credit: u32,
+    pub title: String,
+    pub title_slug: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContestDetail {
+    pub contest: ContestMeta,
+    pub questions: Vec<ContestQuestion>,
+    pub user_num: Option<u32>,
+    pub registered: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContestRankingBadge {
+    pub name: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserContestRanking {
+    pub attendedContestsCount: u32,
+    pub rating: f64,
+    pub globalRanking: u32,
+    pub totalParticipants: u32,
+    pub topPercentage: f64,
+    pub badge: Option<ContestRankingBadge>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HistoryContest {
+    pub title: String,
+    pub startTime: i64,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserContestHistoryNode {
+    pub attended: bool,
+    pub trendDirection: Option<String>,
+    pub problemsSolved: u32,
+    pub totalProblems: u32,
+    pub finishTimeInSeconds: i64,
+    pub rating: f64,
+    pub ranking: u32,
+    pub contest: HistoryContest,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct UserContestData {
+    pub userContestRanking: Option<UserContestRanking>,
+    pub userContestRankingHistory: Option<Vec<UserContestHistoryNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserContest {
+    pub data: UserContestData,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RankingSubmission {
+    pub date: i64,
+    pub question_id: u32,
+    pub submission_id: Option<SubmissionId>,
+    #[serde(default)]
+    pub fail_count: u32,
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RankingEntry {
+    pub username: String,
+    pub user_slug: Option<String>,
+    pub real_name: Option<String>,
+    pub country_name: Option<String>,
+    pub data_region: Option<String>,
+    pub rank: u32,
+    pub score: u32,
+    pub finish_time: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ContestRankingPage {
+    pub time: Option<f64>,
+    pub is_past: Option<bool>,
+    pub user_num: u32,
+    pub questions: Vec<ContestQuestion>,
+    pub total_rank: Vec<RankingEntry>,
+    pub submissions: Vec<HashMap<String, RankingSubmission>>,
+}
@@ -13,6 +13,7 @@ pub struct ProblemsetQuestionListData {
 
 #[derive(Debug, Deserialize)]
 pub struct ProblemsetQuestionList {
+    pub total: Option<u32>,
     pub questions: Vec<Question>,
 }
 
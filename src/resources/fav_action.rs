@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct FavoriteQuestionResult {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub favoriteIdHash: Option<String>,
+    pub questionId: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FavoriteActionData {
+    pub result: FavoriteQuestionResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FavoriteAction {
+    pub data: FavoriteActionData,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct QuestionIdNode {
+    pub questionId: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionIdData {
+    pub question: Option<QuestionIdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionId {
+    pub data: QuestionIdData,
+}
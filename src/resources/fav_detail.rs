@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+use super::fav_list::FavoriteNode;
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct FavoriteDetailData {
+    pub favoriteDetail: Option<FavoriteNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FavoriteDetail {
+    pub data: FavoriteDetailData,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct PublicFavoriteList {
+    pub hasMore: bool,
+    pub totalLength: u32,
+    pub favorites: Vec<FavoriteNode>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct PublicFavoritesData {
+    pub createdPublicFavoriteList: Option<PublicFavoriteList>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicFavorites {
+    pub data: PublicFavoritesData,
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReturnType {
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Output {
+    pub paramindex: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Constructor {
+    pub params: Vec<Param>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Method {
+    pub name: String,
+    pub params: Vec<Param>,
+    #[serde(rename = "return")]
+    pub return_: Option<ReturnType>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MetaData {
+    pub name: Option<String>,
+    pub params: Option<Vec<Param>>,
+    #[serde(rename = "return")]
+    pub return_: Option<ReturnType>,
+    pub output: Option<Output>,
+    pub classname: Option<String>,
+    pub constructor: Option<Constructor>,
+    pub methods: Option<Vec<Method>>,
+    pub systemdesign: Option<bool>,
+    pub manual: Option<bool>,
+}
+
+impl MetaData {
+    pub fn is_system_design(&self) -> bool {
+        self.systemdesign.unwrap_or(false) || self.classname.is_some()
+    }
+}
@@ -1,19 +1,25 @@
 pub mod beat_stats;
+pub mod contest;
 pub mod cookie;
 pub mod data_profile;
 pub mod descr;
+pub mod fav_action;
+pub mod fav_detail;
 pub mod fav_list;
 pub mod lang_stats;
+pub mod meta_data;
 pub mod notification;
 pub mod problemfulldata;
 pub mod pub_data_profile;
 pub mod skill_stats;
+pub mod subm_details;
+pub mod subm_dump;
 pub mod subm_list;
 pub mod subm_send;
 pub mod subm_show;
 pub mod test_send;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Debug)]
 pub struct Rate {
@@ -26,3 +32,19 @@ pub struct Description {
     pub name: String,
     pub content: String,
 }
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct Example {
+    pub input: String,
+    pub output: String,
+    pub explanation: Option<String>,
+    pub image: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct DescriptionSections {
+    pub statement: String,
+    pub examples: Vec<Example>,
+    pub constraints: Vec<String>,
+    pub follow_up: Option<String>,
+}
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct NotificationsData {
@@ -25,7 +25,7 @@ pub struct NotificationEdge {
 }
 
 #[allow(non_snake_case)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct NotificationNode {
     pub id: String,
     pub notificationId: i32,
@@ -35,7 +35,7 @@ pub struct NotificationNode {
     pub __typename: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct NotificationDataNode {
     pub id: String,
     pub content: String,
@@ -48,7 +48,23 @@ pub struct NotificationDataNode {
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct PageInfo {
-    pub endCursor: String,
+    pub endCursor: Option<String>,
     pub hasNextPage: bool,
     pub __typename: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationActionResult {
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationActionData {
+    pub result: NotificationActionResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationAction {
+    pub data: NotificationActionData,
+}
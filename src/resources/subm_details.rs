@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+use crate::{submissions::SubmStatus, ProgrammingLanguage};
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmLang {
+    pub name: String,
+    pub verboseName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmQuestion {
+    pub questionId: String,
+    pub titleSlug: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmUser {
+    pub username: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmissionDetailsNode {
+    pub runtime: Option<i64>,
+    pub runtimeDisplay: Option<String>,
+    pub runtimePercentile: Option<f64>,
+    pub runtimeDistribution: Option<String>,
+    pub memory: Option<i64>,
+    pub memoryDisplay: Option<String>,
+    pub memoryPercentile: Option<f64>,
+    pub memoryDistribution: Option<String>,
+    pub code: String,
+    pub timestamp: i64,
+    pub statusCode: i32,
+    pub user: Option<SubmUser>,
+    pub lang: SubmLang,
+    pub question: SubmQuestion,
+    pub notes: Option<String>,
+    pub runtimeError: Option<String>,
+    pub compileError: Option<String>,
+    pub lastTestcase: Option<String>,
+    pub codeOutput: Option<String>,
+    pub expectedOutput: Option<String>,
+    pub totalCorrect: Option<i32>,
+    pub totalTestcases: Option<i32>,
+    pub stdOutput: Option<String>,
+}
+
+impl SubmissionDetailsNode {
+    pub fn verdict(&self) -> Option<SubmStatus> {
+        SubmStatus::from_status_code(self.statusCode)
+    }
+
+    pub fn language(&self) -> Option<ProgrammingLanguage> {
+        ProgrammingLanguage::from_slug(&self.lang.name)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub submissionDetails: Option<SubmissionDetailsNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmissionDetails {
+    pub data: Data,
+}
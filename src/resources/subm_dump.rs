@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+use super::subm_send::SubmissionId;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SubmissionDump {
+    pub id: SubmissionId,
+    pub question_id: Option<u32>,
+    pub lang: String,
+    pub lang_name: Option<String>,
+    pub time: Option<String>,
+    pub timestamp: i64,
+    pub status: Option<i32>,
+    pub status_display: String,
+    pub runtime: Option<String>,
+    pub url: Option<String>,
+    pub is_pending: Option<String>,
+    pub title: String,
+    pub memory: Option<String>,
+    pub code: Option<String>,
+    pub compare_result: Option<String>,
+    pub title_slug: String,
+    pub has_notes: Option<bool>,
+    pub flag_type: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmissionsDumpPage {
+    pub submissions_dump: Vec<SubmissionDump>,
+    pub has_next: bool,
+    pub last_key: Option<String>,
+}
@@ -1,8 +1,64 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Deserialize, Debug)]
+use crate::{error::Errors, ProgrammingLanguage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubmissionId(pub u64);
+
+impl From<u64> for SubmissionId {
+    fn from(id: u64) -> Self {
+        SubmissionId(id)
+    }
+}
+
+impl From<u32> for SubmissionId {
+    fn from(id: u32) -> Self {
+        SubmissionId(u64::from(id))
+    }
+}
+
+impl std::fmt::Display for SubmissionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for SubmissionId {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse::<u64>()
+            .map(SubmissionId)
+            .map_err(|_| Errors::ApiError(format!("Invalid submission id `{}`", s)))
+    }
+}
+
+impl Serialize for SubmissionId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubmissionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawId {
+            Number(u64),
+            Text(String),
+        }
+
+        match RawId::deserialize(deserializer)? {
+            RawId::Number(id) => Ok(SubmissionId(id)),
+            RawId::Text(id) => id.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SubmissionCaseResp {
-    pub submission_id: u32,
+    pub submission_id: SubmissionId,
 }
 
 #[derive(Serialize, Debug)]
@@ -36,9 +92,28 @@ pub struct SubmExecutionResult {
     pub status_memory: Option<String>,
     pub memory_percentile: Option<f32>,
     pub pretty_lang: Option<String>,
-    pub submission_id: Option<String>,
+    pub submission_id: Option<SubmissionId>,
     pub input_formatted: Option<String>,
     pub input: Option<String>,
     pub status_msg: Option<String>,
     pub state: String,
 }
+
+impl SubmExecutionResult {
+    pub fn language(&self) -> Option<ProgrammingLanguage> {
+        ProgrammingLanguage::from_judge_result(self.lang.as_deref(), self.pretty_lang.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubmissionId;
+
+    #[test]
+    fn submission_id_accepts_numbers_and_strings() {
+        let ids = serde_json::from_str::<Vec<SubmissionId>>(r#"[1234567890123, "42"]"#).unwrap();
+        assert_eq!(ids, vec![SubmissionId(1234567890123), SubmissionId(42)]);
+        assert_eq!(serde_json::to_string(&ids[1]).unwrap(), "42");
+        assert!(serde_json::from_str::<SubmissionId>(r#""abc""#).is_err());
+    }
+}
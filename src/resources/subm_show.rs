@@ -1,9 +1,11 @@
 use serde::Deserialize;
 
+use super::subm_send::SubmissionId;
+
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct SubmissionDumpNode {
-    pub id: String,
+    pub id: SubmissionId,
     pub statusDisplay: String,
     pub lang: String,
     pub runtime: String,
@@ -1,5 +1,29 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ProgrammingLanguage;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct InterpretId(pub String);
+
+impl From<String> for InterpretId {
+    fn from(id: String) -> Self {
+        InterpretId(id)
+    }
+}
+
+impl From<&str> for InterpretId {
+    fn from(id: &str) -> Self {
+        InterpretId(String::from(id))
+    }
+}
+
+impl std::fmt::Display for InterpretId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct TestCase {
     pub question_id: String,
@@ -9,9 +33,9 @@ pub struct TestCase {
     pub judge_type: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TestCaseResp {
-    pub interpret_id: String,
+    pub interpret_id: InterpretId,
     pub test_case: String,
 }
 
@@ -53,3 +77,31 @@ pub struct TestExecutionResult {
     pub status_msg: Option<String>,
     pub state: String,
 }
+
+impl TestExecutionResult {
+    pub fn language(&self) -> Option<ProgrammingLanguage> {
+        ProgrammingLanguage::from_judge_result(self.lang.as_deref(), self.pretty_lang.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterpretId, TestCaseResp};
+
+    #[test]
+    fn interpret_id_is_a_plain_string() {
+        let resp = serde_json::from_str::<TestCaseResp>(
+            r#"{"interpret_id": "runcode_1700000000.123_abc", "test_case": "[2,7]\n9"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            resp.interpret_id,
+            InterpretId::from("runcode_1700000000.123_abc")
+        );
+        assert_eq!(resp.interpret_id.to_string(), "runcode_1700000000.123_abc");
+        assert_eq!(
+            serde_json::to_string(&resp.interpret_id).unwrap(),
+            r#""runcode_1700000000.123_abc""#
+        );
+    }
+}
@@ -0,0 +1,572 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::{error::Errors, problem_actions::Problem, ProgrammingLanguage};
+
+#[derive(Debug, Clone)]
+pub struct RustScaffold {
+    pub crate_name: String,
+    pub source: String,
+}
+
+impl RustScaffold {
+    pub async fn write_crate(&self, dir: &str) -> Result<(), Errors> {
+        let dir = Path::new(dir);
+        tokio::fs::create_dir_all(dir.join("src")).await?;
+
+        let manifest = format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n",
+            self.crate_name
+        );
+        tokio::fs::write(dir.join("Cargo.toml"), manifest).await?;
+        tokio::fs::write(dir.join("src").join("lib.rs"), &self.source).await?;
+        Ok(())
+    }
+
+    pub async fn write_module(&self, path: &str) -> Result<(), Errors> {
+        let path = Path::new(path);
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(path, &self.source).await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Signature {
+    pub(crate) name: String,
+    pub(crate) params: Vec<(String, String)>,
+    pub(crate) ret: Option<String>,
+}
+
+pub(crate) fn parse_signatures(snippet: &str) -> Vec<Signature> {
+    let mut signatures = Vec::new();
+
+    for line in snippet.lines() {
+        let line = line.trim();
+        if line.starts_with("//") {
+            continue;
+        }
+        let Some(start) = line.find("fn ") else {
+            continue;
+        };
+        let rest = &line[start + 3..];
+        let (Some(open), Some(close)) = (rest.find('('), rest.rfind(')')) else {
+            continue;
+        };
+
+        let name = rest[..open].trim().to_owned();
+        let params = split_top_level(&rest[open + 1..close])
+            .into_iter()
+            .filter_map(|param| {
+                let (name, ty) = param.split_once(':')?;
+                Some((name.trim().to_owned(), ty.trim().to_owned()))
+            })
+            .collect();
+        let ret = rest[close + 1..]
+            .trim()
+            .strip_prefix("->")
+            .map(|ret| ret.trim_end_matches('{').trim().to_owned())
+            .filter(|ret| !ret.is_empty());
+
+        signatures.push(Signature { name, params, ret });
+    }
+
+    signatures
+}
+
+fn split_top_level(params: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for ch in params.chars() {
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+    if !current.trim().is_empty() {
+        parts.push(current);
+    }
+
+    parts
+}
+
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
+}
+
+fn fill_empty_bodies(snippet: &str) -> String {
+    let lines = snippet.lines().collect::<Vec<&str>>();
+    let mut filled = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        filled.push(String::from(line));
+        i += 1;
+
+        let trimmed = line.trim();
+        let is_fn = !trimmed.starts_with("//") && trimmed.contains("fn ") && trimmed.ends_with('{');
+        if !is_fn || !trimmed.contains("->") {
+            continue;
+        }
+
+        let mut j = i;
+        while j < lines.len() && lines[j].trim().is_empty() {
+            j += 1;
+        }
+        if j < lines.len() && lines[j].trim() == "}" {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            filled.push(format!("{}    todo!()", indent));
+            i = j;
+        }
+    }
+
+    filled.join("\n")
+}
+
+pub(crate) fn rust_literal(value: &Value, ty: &str) -> Option<String> {
+    let ty = ty.trim();
+
+    match ty {
+        "i32" | "i64" | "u32" | "u64" | "usize" | "isize" | "i16" | "u16" | "u8" | "i8" => {
+            if value.is_i64() || value.is_u64() {
+                Some(value.to_string())
+            } else {
+                None
+            }
+        }
+        "f64" | "f32" => value.as_f64().map(|num| format!("{:?}", num)),
+        "bool" => value.as_bool().map(|flag| flag.to_string()),
+        "String" => value
+            .as_str()
+            .map(|text| format!("String::from({:?})", text)),
+        "char" => {
+            let text = value.as_str()?;
+            let mut chars = text.chars();
+            let ch = chars.next()?;
+            chars.next().is_none().then(|| format!("{:?}", ch))
+        }
+        "Option<Box<ListNode>>" => {
+            let values = value
+                .as_array()?
+                .iter()
+                .map(|val| val.as_i64().map(|val| val.to_string()))
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("list_from_vec(&[{}])", values.join(", ")))
+        }
+        "Option<Rc<RefCell<TreeNode>>>" => {
+            let values = value
+                .as_array()?
+                .iter()
+                .map(|val| match val {
+                    Value::Null => Some(String::from("None")),
+                    val => val.as_i64().map(|val| format!("Some({})", val)),
+                })
+                .collect::<Option<Vec<String>>>()?;
+            Some(format!("tree_from_vec(&[{}])", values.join(", ")))
+        }
+        _ => {
+            if let Some(inner) = ty.strip_prefix("Vec<").and_then(|ty| ty.strip_suffix('>')) {
+                let values = value
+                    .as_array()?
+                    .iter()
+                    .map(|val| rust_literal(val, inner))
+                    .collect::<Option<Vec<String>>>()?;
+                return Some(format!("vec![{}]", values.join(", ")));
+            }
+            if let Some(inner) = ty
+                .strip_prefix("Option<")
+                .and_then(|ty| ty.strip_suffix('>'))
+            {
+                return match value {
+                    Value::Null => Some(String::from("None")),
+                    value => rust_literal(value, inner).map(|lit| format!("Some({})", lit)),
+                };
+            }
+            None
+        }
+    }
+}
+
+pub(crate) fn parse_value(raw: &str) -> Option<Value> {
+    serde_json::from_str::<Value>(raw.trim()).ok()
+}
+
+// The generated code shares its node definitions with `crate::helpers`, so these
+// files must not contain top-level `use` items that could clash with the snippet.
+const LIST_NODE: &str = include_str!("helpers/list_node.rs");
+
+const TREE_NODE: &str = include_str!("helpers/tree_node.rs");
+
+pub(crate) fn rust_scaffold(problem: &Problem) -> Result<RustScaffold, Errors> {
+    let question = &problem.full_data.data.question;
+    let snippet = problem
+        .snippet(ProgrammingLanguage::Rust)
+        .ok_or_else(|| Errors::ApiError("Rust is not supported for this problem".into()))?;
+    let meta_data = problem.meta_data()?;
+
+    let mut source = format!(
+        "// {}. {}\n// https://leetcode.com/problems/{}/\n\n#![allow(dead_code, unused_variables, unused_mut)]\n\n",
+        question.questionFrontendId, question.title, question.titleSlug
+    );
+
+    if snippet.contains("ListNode") {
+        source.push_str(LIST_NODE);
+        source.push('\n');
+    }
+    if snippet.contains("TreeNode") {
+        source.push_str(TREE_NODE);
+        source.push('\n');
+    }
+    if !meta_data.is_system_design() {
+        source.push_str("pub struct Solution;\n\n");
+    }
+    source.push_str(fill_empty_bodies(&snippet).trim_end());
+    source.push('\n');
+
+    let signatures = parse_signatures(&snippet);
+    let inputs = problem.example_inputs()?;
+    let outputs = problem
+        .description_sections()
+        .examples
+        .into_iter()
+        .map(|example| example.output)
+        .collect::<Vec<String>>();
+
+    let tests = inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let body = if meta_data.is_system_design() {
+                design_test_body(&meta_data, &signatures, input, outputs.get(i))
+            } else {
+                solution_test_body(&meta_data, &signatures, input, outputs.get(i))
+            };
+            match body {
+                Some(body) => format!("    #[test]\n    fn example_{}() {{\n{}    }}\n", i + 1, body),
+                None => format!(
+                    "    #[test]\n    #[ignore = \"example could not be converted automatically\"]\n    fn example_{}() {{}}\n",
+                    i + 1
+                ),
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if !tests.is_empty() {
+        source.push_str("\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n");
+        source.push_str(&tests.join("\n"));
+        source.push_str("}\n");
+    }
+
+    Ok(RustScaffold {
+        crate_name: format!(
+            "p{:0>4}_{}",
+            question.questionFrontendId,
+            question.titleSlug.replace('-', "_")
+        ),
+        source,
+    })
+}
+
+fn solution_test_body(
+    meta_data: &crate::resources::meta_data::MetaData,
+    signatures: &[Signature],
+    input: &[String],
+    output: Option<&String>,
+) -> Option<String> {
+    let signature = signatures
+        .iter()
+        .find(|signature| meta_data.name.as_deref().map(snake_case) == Some(signature.name.clone()))
+        .or_else(|| signatures.first())?;
+    if signature.params.len() != input.len() {
+        return None;
+    }
+
+    let mut body = String::new();
+    let mut args = Vec::new();
+    let mut mutated = None;
+
+    for ((name, ty), raw) in signature.params.iter().zip(input) {
+        let value = parse_value(raw)?;
+        if let Some(inner) = ty.strip_prefix("&mut ") {
+            body.push_str(&format!(
+                "        let mut {}: {} = {};\n",
+                name,
+                inner,
+                rust_literal(&value, inner)?
+            ));
+            args.push(format!("&mut {}", name));
+            mutated.get_or_insert((name.clone(), String::from(inner)));
+        } else if let Some(inner) = ty.strip_prefix('&') {
+            body.push_str(&format!(
+                "        let {}: {} = {};\n",
+                name,
+                inner,
+                rust_literal(&value, inner)?
+            ));
+            args.push(format!("&{}", name));
+        } else {
+            body.push_str(&format!(
+                "        let {}: {} = {};\n",
+                name,
+                ty,
+                rust_literal(&value, ty)?
+            ));
+            args.push(name.clone());
+        }
+    }
+
+    let call = format!("Solution::{}({})", signature.name, args.join(", "));
+    let expected = output.and_then(|output| parse_value(output))?;
+
+    match (&signature.ret, mutated) {
+        (Some(ret), _) => {
+            body.push_str(&format!("        let result = {};\n", call));
+            body.push_str(&assertion("result", ret, &expected)?);
+        }
+        (None, Some((name, ty))) => {
+            body.push_str(&format!("        {};\n", call));
+            body.push_str(&assertion(&name, &ty, &expected)?);
+        }
+        (None, None) => body.push_str(&format!("        {};\n", call)),
+    }
+
+    Some(body)
+}
+
+fn design_test_body(
+    meta_data: &crate::resources::meta_data::MetaData,
+    signatures: &[Signature],
+    input: &[String],
+    output: Option<&String>,
+) -> Option<String> {
+    let class_name = meta_data.classname.as_deref()?;
+    let calls = parse_value(input.first()?)?;
+    let calls = calls.as_array()?;
+    let args = parse_value(input.get(1)?)?;
+    let args = args.as_array()?;
+    let expected = output.and_then(|output| parse_value(output));
+    let expected = expected.as_ref().and_then(|expected| expected.as_array());
+
+    let mut body = String::new();
+    for (i, (call, call_args)) in calls.iter().zip(args).enumerate() {
+        let call = call.as_str()?;
+        let call_args = call_args.as_array()?;
+        let method = if i == 0 && call == class_name {
+            String::from("new")
+        } else {
+            snake_case(call)
+        };
+        let signature = signatures
+            .iter()
+            .find(|signature| signature.name == method)?;
+
+        let params = signature
+            .params
+            .iter()
+            .filter(|(name, _)| !name.ends_with("self"))
+            .collect::<Vec<&(String, String)>>();
+        if params.len() != call_args.len() {
+            return None;
+        }
+        let args = params
+            .iter()
+            .zip(call_args)
+            .map(|((_, ty), value)| rust_literal(value, ty))
+            .collect::<Option<Vec<String>>>()?;
+
+        if method == "new" {
+            body.push_str(&format!(
+                "        let mut obj = {}::new({});\n",
+                class_name,
+                args.join(", ")
+            ));
+            continue;
+        }
+
+        let call = format!("obj.{}({})", method, args.join(", "));
+        let expected = expected
+            .and_then(|expected| expected.get(i))
+            .filter(|expected| !expected.is_null());
+        match (&signature.ret, expected) {
+            (Some(ret), Some(expected)) => {
+                body.push_str(&format!("        let result = {};\n", call));
+                body.push_str(&assertion("result", ret, expected)?);
+            }
+            _ => body.push_str(&format!("        {};\n", call)),
+        }
+    }
+
+    Some(body)
+}
+
+fn assertion(actual: &str, ty: &str, expected: &Value) -> Option<String> {
+    let literal = rust_literal(expected, ty)?;
+    if ty == "f64" || ty == "f32" {
+        return Some(format!(
+            "        assert!(({} - {}).abs() < 1e-5);\n",
+            actual, literal
+        ));
+    }
+    Some(format!("        assert_eq!({}, {});\n", actual, literal))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{fill_empty_bodies, parse_signatures, rust_literal};
+    use crate::fixtures;
+
+    #[test]
+    fn parses_solution_and_design_signatures() {
+        let signatures = parse_signatures(
+            "impl Solution {\n    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n    }\n}",
+        );
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(signatures[0].name, "two_sum");
+        assert_eq!(
+            signatures[0].params,
+            vec![
+                (String::from("nums"), String::from("Vec<i32>")),
+                (String::from("target"), String::from("i32")),
+            ]
+        );
+        assert_eq!(signatures[0].ret.as_deref(), Some("Vec<i32>"));
+
+        let signatures = parse_signatures(
+            "// fn commented(x: i32) -> i32 {\nimpl MinStack {\n    fn new() -> Self {\n    }\n    fn push(&self, val: i32) {\n    }\n    fn merge(a: HashMap<i32, Vec<i32>>, b: &mut Vec<(i32, i32)>) {\n    }\n}",
+        );
+        let names = signatures
+            .iter()
+            .map(|signature| signature.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["new", "push", "merge"]);
+        assert_eq!(signatures[0].ret.as_deref(), Some("Self"));
+        assert_eq!(signatures[1].ret, None);
+        assert_eq!(
+            signatures[2].params,
+            vec![
+                (String::from("a"), String::from("HashMap<i32, Vec<i32>>")),
+                (String::from("b"), String::from("&mut Vec<(i32, i32)>")),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_values_to_rust_literals() {
+        assert_eq!(
+            rust_literal(&json!([[1, 2], [3]]), "Vec<Vec<i32>>").as_deref(),
+            Some("vec![vec![1, 2], vec![3]]")
+        );
+        assert_eq!(
+            rust_literal(&json!("ab"), "String").as_deref(),
+            Some("String::from(\"ab\")")
+        );
+        assert_eq!(rust_literal(&json!("a"), "char").as_deref(), Some("'a'"));
+        assert_eq!(rust_literal(&json!("ab"), "char"), None);
+        assert_eq!(rust_literal(&json!(2.5), "f64").as_deref(), Some("2.5"));
+        assert_eq!(rust_literal(&json!(2.5), "i32"), None);
+        assert_eq!(
+            rust_literal(&json!([1, 2]), "Option<Box<ListNode>>").as_deref(),
+            Some("list_from_vec(&[1, 2])")
+        );
+        assert_eq!(
+            rust_literal(&json!([1, null, 2]), "Option<Rc<RefCell<TreeNode>>>").as_deref(),
+            Some("tree_from_vec(&[Some(1), None, Some(2)])")
+        );
+        assert_eq!(
+            rust_literal(&json!(null), "Option<i32>").as_deref(),
+            Some("None")
+        );
+    }
+
+    #[test]
+    fn fills_only_empty_bodies_that_return() {
+        let filled = fill_empty_bodies(
+            "impl MinStack {\n    fn new() -> Self {\n        \n    }\n\n    fn push(&self, val: i32) {\n        \n    }\n\n    fn top(&self) -> i32 {\n        self.top\n    }\n}",
+        );
+        assert_eq!(
+            filled,
+            "impl MinStack {\n    fn new() -> Self {\n        todo!()\n    }\n\n    fn push(&self, val: i32) {\n        \n    }\n\n    fn top(&self) -> i32 {\n        self.top\n    }\n}"
+        );
+    }
+
+    #[test]
+    fn scaffolds_two_sum() {
+        let scaffold = fixtures::two_sum().rust_scaffold().unwrap();
+
+        assert_eq!(scaffold.crate_name, "p0001_two_sum");
+        assert!(scaffold
+            .source
+            .starts_with("// 1. Two Sum\n// https://leetcode.com/problems/two-sum/\n"));
+        assert!(scaffold.source.contains("pub struct Solution;\n"));
+        assert!(scaffold.source.contains(
+            "    pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {\n        todo!()\n    }"
+        ));
+        assert!(scaffold.source.contains(
+            "    fn example_2() {\n        let nums: Vec<i32> = vec![3, 2, 4];\n        let target: i32 = 6;\n        let result = Solution::two_sum(nums, target);\n        assert_eq!(result, vec![1, 2]);\n    }"
+        ));
+        assert!(!scaffold.source.contains("struct ListNode"));
+    }
+
+    #[test]
+    fn scaffolds_list_and_tree_problems() {
+        let scaffold = fixtures::merge_two_lists().rust_scaffold().unwrap();
+        assert!(scaffold.source.contains("pub struct ListNode {"));
+        assert!(!scaffold.source.contains("pub struct TreeNode {"));
+        assert!(scaffold.source.contains(
+            "        let list1: Option<Box<ListNode>> = list_from_vec(&[1, 2, 4]);\n        let list2: Option<Box<ListNode>> = list_from_vec(&[1, 3, 4]);\n        let result = Solution::merge_two_lists(list1, list2);\n        assert_eq!(result, list_from_vec(&[1, 1, 2, 3, 4, 4]));\n"
+        ));
+
+        let scaffold = fixtures::invert_tree().rust_scaffold().unwrap();
+        assert!(scaffold.source.contains("pub struct TreeNode {"));
+        assert!(scaffold.source.contains(
+            "        let result = Solution::invert_tree(root);\n        assert_eq!(result, tree_from_vec(&[Some(4), Some(7), Some(2), Some(9), Some(6), Some(3), Some(1)]));\n"
+        ));
+    }
+
+    #[test]
+    fn scaffolds_design_problem() {
+        let scaffold = fixtures::min_stack().rust_scaffold().unwrap();
+
+        assert!(!scaffold.source.contains("pub struct Solution;"));
+        assert!(scaffold.source.contains(
+            "        let mut obj = MinStack::new();\n        obj.push(-2);\n        obj.push(0);\n        let result = obj.get_min();\n        assert_eq!(result, -2);\n        obj.pop();\n        let result = obj.top();\n        assert_eq!(result, -2);\n"
+        ));
+    }
+
+    #[test]
+    fn scaffolds_mutated_argument() {
+        let scaffold = fixtures::rotate_without_output().rust_scaffold().unwrap();
+
+        assert!(scaffold.source.contains(
+            "        let mut nums: Vec<i32> = vec![1, 2, 3];\n        let k: i32 = 1;\n        Solution::rotate(&mut nums, k);\n        assert_eq!(nums, vec![3, 1, 2]);\n"
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::ProgrammingLanguage;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubmStatus {
+    Accepted,
+    WrongAnswer,
+    TimeLimitExceeded,
+    MemoryLimitExceeded,
+    OutputLimitExceeded,
+    RuntimeError,
+    CompileError,
+    InternalError,
+}
+
+impl SubmStatus {
+    pub fn from_status_code(status_code: i32) -> Option<SubmStatus> {
+        match status_code {
+            10 => Some(SubmStatus::Accepted),
+            11 => Some(SubmStatus::WrongAnswer),
+            12 => Some(SubmStatus::MemoryLimitExceeded),
+            13 => Some(SubmStatus::OutputLimitExceeded),
+            14 => Some(SubmStatus::TimeLimitExceeded),
+            15 => Some(SubmStatus::RuntimeError),
+            16 => Some(SubmStatus::InternalError),
+            20 => Some(SubmStatus::CompileError),
+            _ => None,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SubmStatus::Accepted => "Accepted",
+            SubmStatus::WrongAnswer => "Wrong Answer",
+            SubmStatus::TimeLimitExceeded => "Time Limit Exceeded",
+            SubmStatus::MemoryLimitExceeded => "Memory Limit Exceeded",
+            SubmStatus::OutputLimitExceeded => "Output Limit Exceeded",
+            SubmStatus::RuntimeError => "Runtime Error",
+            SubmStatus::CompileError => "Compile Error",
+            SubmStatus::InternalError => "Internal Error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionFilter {
+    pub(crate) statuses: Vec<SubmStatus>,
+    pub(crate) langs: Vec<String>,
+    pub(crate) since: Option<i64>,
+    pub(crate) until: Option<i64>,
+}
+
+impl SubmissionFilter {
+    pub fn set_statuses(mut self, statuses: Vec<SubmStatus>) -> SubmissionFilter {
+        self.statuses = statuses;
+        self
+    }
+
+    pub fn set_langs(mut self, langs: Vec<ProgrammingLanguage>) -> SubmissionFilter {
+        self.langs = langs
+            .into_iter()
+            .map(|lang| String::from(lang.slug()))
+            .collect();
+        self
+    }
+
+    pub fn set_date_range(mut self, since: Option<i64>, until: Option<i64>) -> SubmissionFilter {
+        self.since = since;
+        self.until = until;
+        self
+    }
+
+    pub(crate) fn matches(&self, status_display: &str, lang: &str, timestamp: i64) -> bool {
+        let status_ok = self.statuses.is_empty()
+            || self
+                .statuses
+                .iter()
+                .any(|status| status.display_name() == status_display);
+        let lang_ok = self.langs.is_empty() || self.langs.iter().any(|slug| slug == lang);
+        let since_ok = !self.is_older_than_range(timestamp);
+        let until_ok = !self.is_newer_than_range(timestamp);
+
+        status_ok && lang_ok && since_ok && until_ok
+    }
+
+    pub(crate) fn is_older_than_range(&self, timestamp: i64) -> bool {
+        self.since.is_some_and(|since| timestamp < since)
+    }
+
+    fn is_newer_than_range(&self, timestamp: i64) -> bool {
+        self.until.is_some_and(|until| timestamp > until)
+    }
+}